@@ -0,0 +1,15 @@
+//! Helpers shared by the benchmarks.
+
+use std::env;
+
+/// Returns the font to benchmark with, from `SDL2_TTF_BENCH_FONT`, or prints
+/// how to set it and returns `None`.
+pub fn bench_font_path() -> Option<String> {
+    match env::var("SDL2_TTF_BENCH_FONT") {
+        Ok(path) => Some(path),
+        Err(_) => {
+            println!("Set SDL2_TTF_BENCH_FONT to a font file to run this benchmark");
+            None
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Compares rendering ASCII text through the UTF-8 and the Latin-1 paths.
+//!
+//! Run with `SDL2_TTF_BENCH_FONT=/path/to/font.ttf cargo bench`.
+
+#[macro_use]
+extern crate criterion;
+extern crate sdl2;
+extern crate sdl2_ttf;
+
+mod common;
+
+use std::path::Path;
+
+use criterion::{black_box, Criterion};
+use sdl2::pixels::Color;
+
+use common::bench_font_path;
+
+fn render_latin1(c: &mut Criterion) {
+    let font_path = match bench_font_path() {
+        Some(path) => path,
+        None => return,
+    };
+    let ttf_context = sdl2_ttf::init().unwrap();
+    let font = ttf_context.load_font(Path::new(&font_path), 16).unwrap();
+    let text = "The quick brown fox jumps over the lazy dog";
+    let color = Color::RGB(255, 255, 255);
+
+    c.bench_function("render solid", |b| {
+        b.iter(|| font.render(black_box(text)).solid(color).unwrap())
+    });
+    c.bench_function("render_latin1 solid", |b| {
+        b.iter(|| font.render_latin1(black_box(text.as_bytes())).solid(color).unwrap())
+    });
+    c.bench_function("render blended", |b| {
+        b.iter(|| font.render(black_box(text)).blended(color).unwrap())
+    });
+    c.bench_function("render_latin1 blended", |b| {
+        b.iter(|| font.render_latin1(black_box(text.as_bytes())).blended(color).unwrap())
+    });
+}
+
+criterion_group!(benches, render_latin1);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+//! Compares the cost of the solid, shaded and blended rendering modes for
+//! short, medium and long text at several sizes, with and without kerning.
+//!
+//! Run with `SDL2_TTF_BENCH_FONT=/path/to/font.ttf cargo bench`.
+
+#[macro_use]
+extern crate criterion;
+extern crate sdl2;
+extern crate sdl2_ttf;
+
+mod common;
+
+use std::path::Path;
+
+use criterion::{black_box, BenchmarkId, Criterion};
+use sdl2::pixels::Color;
+use sdl2_ttf::RenderMode;
+
+use common::bench_font_path;
+
+static POINT_SIZES: [u16; 3] = [12, 24, 48];
+
+static TEXTS: [(&str, &str); 3] = [
+    ("short", "Score"),
+    ("medium", "The quick brown fox jumps over the lazy dog"),
+    ("long", "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod \
+              tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam"),
+];
+
+fn render_modes(c: &mut Criterion) {
+    let font_path = match bench_font_path() {
+        Some(path) => path,
+        None => return,
+    };
+    let ttf_context = sdl2_ttf::init().unwrap();
+    let color = Color::RGB(255, 255, 255);
+    let modes = [
+        ("solid", RenderMode::Solid),
+        ("shaded", RenderMode::Shaded(Color::RGB(0, 0, 0))),
+        ("blended", RenderMode::Blended),
+    ];
+
+    let mut group = c.benchmark_group("render_modes");
+    for &point_size in POINT_SIZES.iter() {
+        let mut font = ttf_context.load_font(Path::new(&font_path), point_size).unwrap();
+        for &kerning in [true, false].iter() {
+            font.set_kerning(kerning);
+            let kerning_name = if kerning { "kerning" } else { "no kerning" };
+            for &(text_name, text) in TEXTS.iter() {
+                for &(mode_name, mode) in modes.iter() {
+                    let parameters = format!("{}pt {} {}", point_size, kerning_name, text_name);
+                    group.bench_function(BenchmarkId::new(mode_name, parameters), |b| {
+                        b.iter(|| font.render(black_box(text)).with_mode(color, mode).unwrap())
+                    });
+                }
+            }
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, render_modes);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+//! Detects the SDL2_ttf version to build against, so that bindings to the
+//! API of newer releases are only compiled when they can be linked.
+//!
+//! The version is queried from `pkg-config` when it is available, and the
+//! `ttf-2-0-18`, `ttf-2-20-0` and `ttf-2-22-0` features select a minimum
+//! version by hand, e.g. on platforms without `pkg-config`. The newest of
+//! all is used.
+
+use std::env;
+use std::process::Command;
+
+/// The SDL2_ttf releases adding API the crate has bindings for.
+const VERSIONS: &[(u32, u32, u32)] = &[(2, 0, 18), (2, 20, 0), (2, 22, 0)];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=PKG_CONFIG_PATH");
+    println!("cargo:rustc-check-cfg=cfg(sdl2_ttf_version, values(any()))");
+    for &(major, minor, patch) in VERSIONS {
+        println!("cargo:rustc-check-cfg=cfg(sdl2_ttf_version_gte_{}_{}_{})",
+            major, minor, patch);
+    }
+
+    let mut version = (2, 0, 14);
+    if env::var_os("CARGO_FEATURE_TTF_2_0_18").is_some() {
+        version = (2, 0, 18);
+    }
+    if env::var_os("CARGO_FEATURE_TTF_2_20_0").is_some() {
+        version = (2, 20, 0);
+    }
+    if env::var_os("CARGO_FEATURE_TTF_2_22_0").is_some() {
+        version = (2, 22, 0);
+    }
+    if let Some(detected) = pkg_config_version() {
+        if detected > version {
+            version = detected;
+        }
+        println!("cargo:rustc-cfg=sdl2_ttf_version=\"{}_{}_{}\"",
+            detected.0, detected.1, detected.2);
+    }
+
+    for &(major, minor, patch) in VERSIONS {
+        if version >= (major, minor, patch) {
+            println!("cargo:rustc-cfg=sdl2_ttf_version_gte_{}_{}_{}", major, minor, patch);
+        }
+    }
+}
+
+/// Returns the version of SDL2_ttf reported by `pkg-config`, if any.
+fn pkg_config_version() -> Option<(u32, u32, u32)> {
+    let output = match Command::new("pkg-config").args(["--modversion", "SDL2_ttf"]).output() {
+        Ok(ref output) if output.status.success() => output.stdout.clone(),
+        _ => return None,
+    };
+    let output = match String::from_utf8(output) {
+        Ok(output) => output,
+        Err(_) => return None,
+    };
+    let mut parts = output.trim().split('.').map(|part| part.parse::<u32>());
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch))) => Some((major, minor, patch)),
+        _ => None,
+    }
+}
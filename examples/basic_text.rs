@@ -0,0 +1,67 @@
+//! The basic flow of drawing text: render it to a surface with a font, turn
+//! the surface into a texture, and copy that to the window.
+//!
+//! Run with `cargo run --example basic_text /path/to/font.ttf`.
+
+extern crate sdl2;
+extern crate sdl2_ttf;
+
+use std::env;
+use std::path::Path;
+
+use sdl2::event::Event;
+use sdl2::keyboard::Keycode;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::TextureQuery;
+
+use sdl2_ttf::Sdl2TtfContext;
+
+fn run(font_path: &Path) {
+    // initialize SDL2 and its video subsystem
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsys = sdl_context.video().unwrap();
+
+    // initialize SDL2_ttf; fonts borrow the context, so it must outlive them
+    let ttf_context = Sdl2TtfContext::init_or_get().unwrap();
+
+    let window = video_subsys.window("SDL2_ttf basic text", 640, 240)
+        .position_centered()
+        .build()
+        .unwrap();
+    let mut renderer = window.renderer().build().unwrap();
+
+    // load a font at 48 points
+    let font = ttf_context.load_font(font_path, 48).unwrap();
+
+    // render the text to a surface, then upload it as a texture
+    let surface = font.render("Hello, SDL2_ttf!")
+        .blended(Color::RGB(255, 255, 255)).unwrap();
+    let texture = renderer.create_texture_from_surface(&surface).unwrap();
+    let TextureQuery { width, height, .. } = texture.query();
+
+    // draw the texture at its natural size in the top left corner, and show it
+    renderer.set_draw_color(Color::RGB(40, 40, 60));
+    renderer.clear();
+    renderer.copy(&texture, None, Some(Rect::new(20, 20, width, height))).unwrap();
+    renderer.present();
+
+    'mainloop: loop {
+        for event in sdl_context.event_pump().unwrap().poll_iter() {
+            match event {
+                Event::Quit{..} => break 'mainloop,
+                Event::KeyDown {keycode: Some(Keycode::Escape), ..} => break 'mainloop,
+                _ => {}
+            }
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<_> = env::args().collect();
+    if args.len() < 2 {
+        println!("Usage: ./basic_text font.[ttf|ttc|fon]")
+    } else {
+        run(Path::new(&args[1]));
+    }
+}
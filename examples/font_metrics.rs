@@ -0,0 +1,49 @@
+//! Prints the metrics of a font, e.g. to find out why text is laid out
+//! differently than expected.
+//!
+//! Run with `cargo run --example font_metrics /path/to/font.ttf [point size]`.
+
+extern crate sdl2_ttf;
+
+use std::env;
+use std::path::Path;
+
+fn run(font_path: &Path, point_size: u16) {
+    let ttf_context = sdl2_ttf::init().unwrap();
+    let font = ttf_context.load_font(font_path, point_size).unwrap();
+
+    println!("linked sdl2_ttf: {}", sdl2_ttf::get_linked_version());
+    println!("font:            {} at {}pt", font_path.display(), point_size);
+    println!("family name:     {}", font.face_family_name().unwrap_or_default());
+    println!("style name:      {}", font.face_style_name().unwrap_or_default());
+    println!("faces:           {}", font.face_count());
+    println!("fixed width:     {}", font.face_is_fixed_width());
+    println!("height:          {}", font.height());
+    println!("ascent:          {}", font.ascent());
+    println!("descent:         {}", font.descent());
+    println!("line skip:       {}", font.recommended_line_spacing());
+
+    for &ch in ['A', 'g', '日'].iter() {
+        if !font.is_glyph_provided32(ch) {
+            println!("{:?}:             not provided", ch);
+            continue;
+        }
+        match font.find_glyph_metrics32(ch) {
+            Some(metrics) => {
+                println!("{:?}:             x {}..{}, y {}..{}, advance {}", ch, metrics.minx,
+                    metrics.maxx, metrics.miny, metrics.maxy, metrics.advance);
+            },
+            None => println!("{:?}:             no metrics", ch),
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<_> = env::args().collect();
+    if args.len() < 2 {
+        println!("Usage: ./font_metrics font.[ttf|ttc|fon] [point size]")
+    } else {
+        let point_size = args.get(2).map_or(16, |size| size.parse().unwrap());
+        run(Path::new(&args[1]), point_size);
+    }
+}
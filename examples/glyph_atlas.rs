@@ -0,0 +1,79 @@
+//! Packs the printable ASCII characters into a glyph atlas, saves it as a
+//! BMP image, or as a PNG image if its path ends in `.png`, and prints where
+//! each glyph is in it, as texture coordinates from 0 to 1 for drawing text
+//! on the GPU.
+//!
+//! Run with
+//! `cargo run --features image --example glyph_atlas /path/to/font.ttf atlas.bmp`.
+
+extern crate image;
+extern crate sdl2;
+extern crate sdl2_ttf;
+
+use std::env;
+use std::path::Path;
+
+use sdl2::pixels::Color;
+use sdl2::surface::Surface;
+use sdl2_ttf::GlyphAtlas;
+
+/// Saves the atlas surface, which is in ARGB8888, as a PNG image with its
+/// alpha channel.
+fn save_png(surface: &Surface, path: &Path) {
+    let (width, height) = surface.size();
+    let pitch = surface.pitch() as usize;
+    let mut rgba = Vec::with_capacity(width as usize * height as usize * 4);
+    surface.with_lock(|pixels| {
+        for row in pixels.chunks(pitch).take(height as usize) {
+            for pixel in row[..width as usize * 4].chunks(4) {
+                let argb = u32::from_ne_bytes([pixel[0], pixel[1], pixel[2], pixel[3]]);
+                rgba.extend_from_slice(&[(argb >> 16) as u8, (argb >> 8) as u8, argb as u8,
+                    (argb >> 24) as u8]);
+            }
+        }
+    });
+    image::save_buffer(path, &rgba, width, height, image::ColorType::Rgba8).unwrap();
+}
+
+/// Saves the atlas surface as a PNG image if the path ends in `.png`, and as
+/// a BMP image otherwise.
+fn save(surface: &Surface, path: &Path) {
+    if path.extension().is_some_and(|extension| extension == "png") {
+        save_png(surface, path);
+    } else {
+        // SDL2 itself can only save BMP images; the atlas keeps its alpha channel
+        surface.save_bmp(path).unwrap();
+    }
+}
+
+fn run(font_path: &Path, atlas_path: &Path) {
+    let ttf_context = sdl2_ttf::init().unwrap();
+    let font = ttf_context.load_font(font_path, 32).unwrap();
+
+    // render the glyphs in white, so they can be tinted when drawn
+    let charset: Vec<char> = (' '..='~').collect();
+    let atlas = GlyphAtlas::build(&font, Color::RGB(255, 255, 255), &charset).unwrap();
+
+    save(atlas.surface(), atlas_path);
+    let (width, height) = atlas.surface().size();
+    println!("saved a {}x{} atlas to {}", width, height, atlas_path.display());
+
+    let (width, height) = (width as f32, height as f32);
+    for &ch in &charset {
+        if let Some(&(rect, ref metrics)) = atlas.get(ch) {
+            println!("{:?}: uv ({:.4}, {:.4})..({:.4}, {:.4}), advance {}", ch,
+                rect.x() as f32 / width, rect.y() as f32 / height,
+                rect.right() as f32 / width, rect.bottom() as f32 / height,
+                metrics.advance);
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<_> = env::args().collect();
+    if args.len() < 3 {
+        println!("Usage: ./glyph_atlas font.[ttf|ttc|fon] atlas.[bmp|png]")
+    } else {
+        run(Path::new(&args[1]), Path::new(&args[2]));
+    }
+}
@@ -0,0 +1,91 @@
+//! Renders Arabic text laid out right to left, above the same text rendered
+//! with the default left to right layout for comparison.
+//! Shaping text needs SDL2_ttf 2.20 built with HarfBuzz, and a font with
+//! Arabic glyphs such as DejaVu Sans or Noto Sans Arabic.
+//!
+//! Run with `cargo run --example rtl_text /path/to/font.ttf`, adding
+//! `--features ttf-2-20-0` if pkg-config cannot detect SDL2_ttf 2.20.
+
+extern crate sdl2;
+extern crate sdl2_ttf;
+
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+mod rtl {
+    use std::env;
+    use std::path::Path;
+
+    use sdl2::event::Event;
+    use sdl2::keyboard::Keycode;
+    use sdl2::pixels::Color;
+    use sdl2::rect::Rect;
+    use sdl2::render::TextureQuery;
+    use sdl2_ttf::Direction;
+
+    /// "Hello world" in Arabic.
+    static TEXT: &str = "\u{645}\u{631}\u{62D}\u{628}\u{627} \
+        \u{628}\u{627}\u{644}\u{639}\u{627}\u{644}\u{645}";
+
+    fn run(font_path: &Path) {
+        let sdl_context = sdl2::init().unwrap();
+        let video_subsys = sdl_context.video().unwrap();
+        let ttf_context = sdl2_ttf::init().unwrap();
+
+        let window = video_subsys.window("SDL2_ttf right to left text", 640, 240)
+            .position_centered()
+            .build()
+            .unwrap();
+        let mut renderer = window.renderer().build().unwrap();
+
+        let mut font = ttf_context.load_font(font_path, 48).unwrap();
+        let color = Color::RGB(255, 255, 255);
+
+        // shaped as Arabic and laid out right to left, which is how it is read
+        font.set_script("Arab").unwrap();
+        font.set_direction(Direction::RightToLeft).unwrap();
+        let right_to_left = font.render(TEXT).blended(color).unwrap();
+
+        // the defaults, which lay the letters out in the wrong order
+        font.set_script("Zzzz").unwrap();
+        font.set_direction(Direction::LeftToRight).unwrap();
+        let left_to_right = font.render(TEXT).blended(color).unwrap();
+
+        renderer.set_draw_color(Color::RGB(40, 40, 60));
+        renderer.clear();
+        for (index, surface) in [right_to_left, left_to_right].iter().enumerate() {
+            let texture = renderer.create_texture_from_surface(surface).unwrap();
+            let TextureQuery { width, height, .. } = texture.query();
+            let target = Rect::new(20, 20 + 110 * index as i32, width, height);
+            renderer.copy(&texture, None, Some(target)).unwrap();
+        }
+        renderer.present();
+
+        'mainloop: loop {
+            for event in sdl_context.event_pump().unwrap().poll_iter() {
+                match event {
+                    Event::Quit{..} => break 'mainloop,
+                    Event::KeyDown {keycode: Some(Keycode::Escape), ..} => break 'mainloop,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    pub fn main() {
+        let args: Vec<_> = env::args().collect();
+        if args.len() < 2 {
+            println!("Usage: ./rtl_text font.[ttf|ttc|fon]")
+        } else {
+            run(Path::new(&args[1]));
+        }
+    }
+}
+
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+fn main() {
+    rtl::main();
+}
+
+#[cfg(not(sdl2_ttf_version_gte_2_20_0))]
+fn main() {
+    println!("This example requires SDL2_ttf 2.20.0 or newer and the `ttf-2-20-0` feature.");
+}
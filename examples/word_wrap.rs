@@ -0,0 +1,74 @@
+//! Renders a paragraph wrapped to the width of the window.
+//! With SDL2_ttf 2.20, the lines are centered with `set_wrapped_align`.
+//!
+//! Run with `cargo run --example word_wrap /path/to/font.ttf`, adding
+//! `--features ttf-2-20-0` if pkg-config cannot detect SDL2_ttf 2.20.
+
+extern crate sdl2;
+extern crate sdl2_ttf;
+
+use std::env;
+use std::path::Path;
+
+use sdl2::event::Event;
+use sdl2::keyboard::Keycode;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::TextureQuery;
+
+static SCREEN_WIDTH : u32 = 640;
+static SCREEN_HEIGHT : u32 = 480;
+static MARGIN : u32 = 20;
+
+static PARAGRAPH: &str = "SDL2_ttf breaks text into lines at spaces when rendering in a \
+    wrapped mode, so that no line is wider than the given width. Lines are also broken \
+    at newlines.\nA word wider than the whole width is broken up wherever it has to be.";
+
+fn run(font_path: &Path) {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsys = sdl_context.video().unwrap();
+    let ttf_context = sdl2_ttf::init().unwrap();
+
+    let window = video_subsys.window("SDL2_ttf word wrap", SCREEN_WIDTH, SCREEN_HEIGHT)
+        .position_centered()
+        .build()
+        .unwrap();
+    let mut renderer = window.renderer().build().unwrap();
+
+    #[allow(unused_mut)]
+    let mut font = ttf_context.load_font(font_path, 24).unwrap();
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    font.set_wrapped_align(sdl2_ttf::WrappedAlign::Center);
+
+    // the surface is only as wide as its longest line
+    let surface = font.render(PARAGRAPH)
+        .blended_wrapped(Color::RGB(255, 255, 255), SCREEN_WIDTH - 2 * MARGIN).unwrap();
+    let texture = renderer.create_texture_from_surface(&surface).unwrap();
+    let TextureQuery { width, height, .. } = texture.query();
+
+    renderer.set_draw_color(Color::RGB(40, 40, 60));
+    renderer.clear();
+    let x = (SCREEN_WIDTH - width) / 2;
+    renderer.copy(&texture, None, Some(Rect::new(x as i32, MARGIN as i32, width, height)))
+        .unwrap();
+    renderer.present();
+
+    'mainloop: loop {
+        for event in sdl_context.event_pump().unwrap().poll_iter() {
+            match event {
+                Event::Quit{..} => break 'mainloop,
+                Event::KeyDown {keycode: Some(Keycode::Escape), ..} => break 'mainloop,
+                _ => {}
+            }
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<_> = env::args().collect();
+    if args.len() < 2 {
+        println!("Usage: ./word_wrap font.[ttf|ttc|fon]")
+    } else {
+        run(Path::new(&args[1]));
+    }
+}
@@ -0,0 +1,164 @@
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{Renderer, Texture};
+use sdl2::surface::Surface;
+
+use font::{
+    blit_layer,
+    create_canvas,
+    Font,
+    FontError,
+    FontResult,
+    GlyphMetrics,
+    RenderMode,
+};
+
+/// The space left between glyphs, so they don't bleed into each other when
+/// the atlas is sampled with filtering.
+const GLYPH_PADDING: u32 = 1;
+
+/// A set of glyphs rendered once and packed into a single surface, so text
+/// can be drawn by copying parts of it, e.g. from a GPU texture.
+pub struct GlyphAtlas {
+    surface: Surface<'static>,
+    glyphs: HashMap<char, (Rect, GlyphMetrics)>,
+}
+
+impl GlyphAtlas {
+    /// Renders every character of `charset` provided by the font in
+    /// *blended* mode, and packs them into shelves of an atlas surface.
+    /// Characters not provided by the font are left out.
+    pub fn build<T>(font: &Font, color: T, charset: &[char])
+            -> FontResult<GlyphAtlas> where T: Into<Color> {
+        let color = color.into();
+        let mut rendered = Vec::new();
+        // each character is only looked at once, however often it is listed
+        let mut seen = HashSet::new();
+        for &ch in charset {
+            if !seen.insert(ch) {
+                continue;
+            }
+            if !font.is_glyph_provided32(ch) {
+                continue;
+            }
+            let metrics = match font.find_glyph_metrics32(ch) {
+                Some(metrics) => metrics,
+                None => continue,
+            };
+            let surface = try!(font.render_char(ch).blended(color));
+            rendered.push((ch, metrics, surface));
+        }
+
+        // taller glyphs first, so each shelf wastes as little space as possible
+        rendered.sort_by_key(|(_, _, surface)| cmp::Reverse(surface.height()));
+
+        let area: u32 = rendered.iter().map(|(_, _, surface)| {
+            (surface.width() + GLYPH_PADDING) * (surface.height() + GLYPH_PADDING)
+        }).sum();
+        let widest = rendered.iter().map(|(_, _, surface)| surface.width() + GLYPH_PADDING)
+            .max().unwrap_or(1);
+        let width = cmp::max(((area as f64).sqrt().ceil() as u32).next_power_of_two(), widest);
+
+        let mut positions = Vec::with_capacity(rendered.len());
+        let (mut x, mut y, mut shelf_height) = (0, 0, 0);
+        for (_, _, surface) in &rendered {
+            if x + surface.width() > width {
+                x = 0;
+                y += shelf_height;
+                shelf_height = 0;
+            }
+            positions.push((x, y));
+            x += surface.width() + GLYPH_PADDING;
+            shelf_height = cmp::max(shelf_height, surface.height() + GLYPH_PADDING);
+        }
+        let height = y + shelf_height;
+
+        let mut canvas = try!(create_canvas(width, height, RenderMode::Blended));
+        let mut glyphs = HashMap::with_capacity(rendered.len());
+        for ((ch, metrics, mut surface), (x, y)) in rendered.into_iter().zip(positions) {
+            try!(blit_layer(&mut surface, &mut canvas, x as i32, y as i32, false));
+            let rect = Rect::new(x as i32, y as i32, surface.width(), surface.height());
+            glyphs.insert(ch, (rect, metrics));
+        }
+        Ok(GlyphAtlas {
+            surface: canvas,
+            glyphs: glyphs,
+        })
+    }
+
+    /// Returns the surface holding all the glyphs.
+    pub fn surface(&self) -> &Surface<'static> {
+        &self.surface
+    }
+
+    /// Returns the location of every glyph in the atlas surface along with
+    /// its metrics.
+    pub fn glyphs(&self) -> &HashMap<char, (Rect, GlyphMetrics)> {
+        &self.glyphs
+    }
+
+    /// Returns the location of the given character in the atlas surface
+    /// along with its metrics, if it was packed.
+    pub fn get(&self, ch: char) -> Option<&(Rect, GlyphMetrics)> {
+        self.glyphs.get(&ch)
+    }
+
+    /// Consumes the atlas, returning its surface.
+    pub fn into_surface(self) -> Surface<'static> {
+        self.surface
+    }
+
+    /// Uploads the atlas to a texture of the given renderer.
+    pub fn as_texture(&self, renderer: &Renderer) -> FontResult<AtlasTexture> {
+        let texture = try!(renderer.create_texture_from_surface(&self.surface)
+            .map_err(|err| FontError::SdlError(err.to_string())));
+        Ok(AtlasTexture {
+            texture: texture,
+            size: self.surface.size(),
+            glyphs: self.glyphs.clone(),
+        })
+    }
+}
+
+/// A glyph atlas uploaded to a texture, ready to draw text with.
+pub struct AtlasTexture {
+    texture: Texture,
+    size: (u32, u32),
+    glyphs: HashMap<char, (Rect, GlyphMetrics)>,
+}
+
+impl AtlasTexture {
+    /// Returns the texture holding all the glyphs.
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    /// Returns the location of the given character in the texture along with
+    /// its metrics, if it was packed.
+    pub fn get(&self, ch: char) -> Option<&(Rect, GlyphMetrics)> {
+        self.glyphs.get(&ch)
+    }
+
+    /// Returns the texture coordinates of the given character, normalized to
+    /// the range 0 to 1, as `(left, top, right, bottom)`.
+    pub fn uv(&self, ch: char) -> Option<(f32, f32, f32, f32)> {
+        let (width, height) = (self.size.0 as f32, self.size.1 as f32);
+        self.glyphs.get(&ch).map(|&(rect, _)| {
+            (rect.left() as f32 / width, rect.top() as f32 / height,
+             rect.right() as f32 / width, rect.bottom() as f32 / height)
+        })
+    }
+
+    /// Draws the given character with its top-left corner at `(x, y)`.
+    /// Characters missing from the atlas are not drawn.
+    pub fn draw_char(&self, renderer: &mut Renderer, ch: char, x: i32, y: i32)
+            -> FontResult<()> {
+        if let Some(&(rect, _)) = self.glyphs.get(&ch) {
+            let target = Rect::new(x, y, rect.width(), rect.height());
+            try!(renderer.copy(&self.texture, Some(rect), Some(target)));
+        }
+        Ok(())
+    }
+}
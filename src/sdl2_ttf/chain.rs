@@ -0,0 +1,141 @@
+use std::cmp;
+use sdl2::pixels::Color;
+use sdl2::surface::Surface;
+
+use font::{
+    blit_layer,
+    create_canvas,
+    Font,
+    FontError,
+    FontResult,
+    RenderMode,
+};
+
+/// A list of fonts to render text with, where each character is rendered
+/// with the first font providing it.
+///
+/// This allows rendering text mixing scripts no single font covers, such as
+/// CJK and Latin.
+pub struct FontChain<'a> {
+    fonts: Vec<Font<'a>>,
+}
+
+impl<'a> FontChain<'a> {
+    /// Creates a font chain from the primary font followed by its fallbacks,
+    /// in order of preference.
+    pub fn new(fonts: Vec<Font<'a>>) -> FontChain<'a> {
+        FontChain { fonts: fonts }
+    }
+
+    /// Returns the fonts of this chain, in order of preference.
+    pub fn fonts(&self) -> &[Font<'a>] {
+        &self.fonts
+    }
+
+    /// Appends a fallback font to the end of the chain.
+    pub fn push(&mut self, font: Font<'a>) {
+        self.fonts.push(font);
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode, rendering
+    /// each character with the first font of the chain providing it.
+    /// Characters provided by none of the fonts are rendered with the
+    /// primary font.
+    pub fn render_str_blended<'b, T>(&self, text: &str, fg: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let fonts: Vec<&Font> = self.fonts.iter().collect();
+        render_with_fallbacks(&fonts, text, fg.into(), RenderMode::Blended)
+    }
+}
+
+/// Splits the text into runs of consecutive characters to render with the
+/// same font, as indices into `fonts`.
+/// Each character goes to the first font providing it, or to the primary
+/// font if none does.
+fn split_runs<'t>(fonts: &[&Font], text: &'t str) -> Vec<(usize, &'t str)> {
+    split_runs_by(text, |ch| {
+        fonts.iter().position(|font| font.is_glyph_provided32(ch)).unwrap_or(0)
+    })
+}
+
+/// Splits the text into runs of consecutive characters for which
+/// `font_index` picks the same font.
+fn split_runs_by<F>(text: &str, font_index: F) -> Vec<(usize, &str)>
+        where F: Fn(char) -> usize {
+    let mut runs = Vec::new();
+    // the font and byte offset the current run started with
+    let mut current: Option<(usize, usize)> = None;
+    for (offset, ch) in text.char_indices() {
+        let index = font_index(ch);
+        match current {
+            Some((current_index, _)) if current_index == index => {},
+            Some((current_index, start)) => {
+                runs.push((current_index, &text[start..offset]));
+                current = Some((index, offset));
+            },
+            None => current = Some((index, offset)),
+        }
+    }
+    if let Some((index, start)) = current {
+        runs.push((index, &text[start..]));
+    }
+    runs
+}
+
+/// Renders text with each character in the first of `fonts` providing it.
+/// The runs rendered by each font are aligned on a common baseline.
+pub fn render_with_fallbacks<'b>(fonts: &[&Font], text: &str, fg: Color,
+        mode: RenderMode) -> FontResult<Surface<'b>> {
+    if fonts.is_empty() {
+        return Err(FontError::SdlError("No font to render with".to_owned()));
+    }
+    let runs = split_runs(fonts, text);
+    if runs.is_empty() {
+        return Err(FontError::SdlError("Text has zero width".to_owned()));
+    }
+
+    let mut width = 0;
+    let mut baseline = 0;
+    let mut below_baseline = 0;
+    for &(index, run) in &runs {
+        let font = fonts[index];
+        let (run_width, _) = try!(font.size_of(run));
+        width += run_width;
+        baseline = cmp::max(baseline, font.ascent());
+        below_baseline = cmp::max(below_baseline, font.height() - font.ascent());
+    }
+
+    let height = cmp::max(baseline + below_baseline, 0) as u32;
+    let mut canvas = try!(create_canvas(width, height, mode));
+    let mut x = 0;
+    for &(index, run) in &runs {
+        let font = fonts[index];
+        let mut surface = try!(font.render(run).with_mode(fg, mode));
+        try!(blit_layer(&mut surface, &mut canvas, x, baseline - font.ascent(), false));
+        x += try!(font.size_of(run)).0 as i32;
+    }
+    Ok(canvas)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_runs_by;
+
+    /// Picks the primary font for ASCII, and the fallback for anything else,
+    /// as a fallback font which also covers ASCII would be picked.
+    fn primary_for_ascii(ch: char) -> usize {
+        if ch.is_ascii() { 0 } else { 1 }
+    }
+
+    #[test]
+    fn switches_back_to_the_primary_font() {
+        let runs = split_runs_by("ab \u{65E5}\u{672C} cd", primary_for_ascii);
+        assert_eq!(runs, vec![(0, "ab "), (1, "\u{65E5}\u{672C}"), (0, " cd")]);
+    }
+
+    #[test]
+    fn keeps_consecutive_characters_of_one_font_together() {
+        assert_eq!(split_runs_by("abc", primary_for_ascii), vec![(0, "abc")]);
+        assert_eq!(split_runs_by("", primary_for_ascii), vec![]);
+    }
+}
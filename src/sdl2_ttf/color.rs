@@ -0,0 +1,27 @@
+use sdl2::pixels::Color;
+
+/// Color arithmetic needed to composite renderings in software.
+pub trait ColorExt {
+    /// Returns the color seen when drawing `over` on top of this color,
+    /// using the usual *over* operator on non-premultiplied alpha.
+    fn alpha_blend(self, over: Color) -> Color;
+}
+
+impl ColorExt for Color {
+    fn alpha_blend(self, over: Color) -> Color {
+        let (dst_r, dst_g, dst_b, dst_a) = self.rgba();
+        let (src_r, src_g, src_b, src_a) = over.rgba();
+        let (src_a, dst_a) = (src_a as u32, dst_a as u32);
+        // alphas scaled by 255 * 255, to keep the arithmetic in integers
+        let dst_weight = dst_a * (255 - src_a);
+        let alpha = src_a * 255 + dst_weight;
+        if alpha == 0 {
+            return Color::RGBA(0, 0, 0, 0);
+        }
+        let channel = |src: u8, dst: u8| {
+            ((src as u32 * src_a * 255 + dst as u32 * dst_weight + alpha / 2) / alpha) as u8
+        };
+        Color::RGBA(channel(src_r, dst_r), channel(src_g, dst_g), channel(src_b, dst_b),
+            ((alpha + 127) / 255) as u8)
+    }
+}
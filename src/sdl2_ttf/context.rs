@@ -1,8 +1,14 @@
 use std::io;
+use std::io::Read;
 use std::error;
 use std::fmt;
 use std::os::raw::{c_int, c_long};
+#[cfg(sdl2_ttf_version_gte_2_0_18)]
+use std::os::raw::c_uint;
 use std::path::Path;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+#[cfg(feature = "global_context")]
+use std::sync::OnceLock;
 use sdl2::get_error;
 use sdl2::rwops::RWops;
 use sdl2::version::Version;
@@ -11,11 +17,28 @@ use font::{
     internal_load_font,
     internal_load_font_at_index,
     internal_load_font_from_ll,
+    internal_load_font_from_data,
     Font,
+    FontResult,
 };
+#[cfg(sdl2_ttf_version_gte_2_0_18)]
+use font::internal_load_font_with_options;
 
 use ffi;
 
+/// Held while changing the FreeType library SDL2_ttf shares between all
+/// fonts, which FreeType does not lock itself.
+static LIBRARY_LOCK: Mutex<()> = Mutex::new(());
+
+/// Locks the FreeType library of SDL2_ttf until the guard is dropped.
+/// Initializing and cleaning up SDL2_ttf, and opening and closing fonts,
+/// must all happen with this lock held, so that fonts can be loaded and
+/// dropped on any thread.
+pub fn lock_library() -> MutexGuard<'static, ()> {
+    // the lock guards no data a panicking thread could leave inconsistent
+    LIBRARY_LOCK.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
 /// A context manager for `SDL2_TTF` to manage C code initialization and clean-up.
 #[must_use]
 pub struct Sdl2TtfContext;
@@ -23,28 +46,76 @@ pub struct Sdl2TtfContext;
 // Clean up the context once it goes out of scope
 impl Drop for Sdl2TtfContext {
     fn drop(&mut self) {
+        let _lock = lock_library();
         unsafe { ffi::TTF_Quit(); }
     }
 }
 
+impl fmt::Debug for Sdl2TtfContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("Sdl2TtfContext")
+            .field("version", &get_linked_version().to_string())
+            .finish()
+    }
+}
+
 impl Sdl2TtfContext {
+    /// Initializes the truetype font API, or takes another reference to it
+    /// if it has already been initialized, e.g. by `init` or by C code.
+    /// SDL2_ttf counts its initializations, so the library is only cleaned
+    /// up once every context has gone out of scope.
+    pub fn init_or_get() -> Result<Sdl2TtfContext, InitError> {
+        let _lock = lock_library();
+        unsafe {
+            if ffi::TTF_Init() == 0 {
+                Ok(Sdl2TtfContext)
+            } else {
+                Err(InitError::InitializationError(
+                    io::Error::last_os_error()
+                        ))
+            }
+        }
+    }
+
     /// Loads a font from the given file with the given size in points.
     pub fn load_font<'a>(&'a self, path: &'a Path, point_size: u16) -> Result<Font, String> {
         internal_load_font(path, point_size)
     }
 
+    /// Loads a font from the given file with the given size in points, and
+    /// calls `f` with it, closing the font once `f` returns.
+    pub fn with_font<F, T>(&self, path: &Path, point_size: u16, f: F) -> FontResult<T>
+            where F: FnOnce(&Font) -> T {
+        let font = try!(self.load_font(path, point_size));
+        Ok(f(&font))
+    }
+
     /// Loads the font at the given index of the file, with the given
     /// size in points.
+    #[cfg_attr(sdl2_ttf_version_gte_2_0_18, deprecated(note = "use `load_font_with_options(path, point_size, index, 0, 0)` instead, \
+        passing 0 as both DPIs to keep the default resolution"))]
     pub fn load_font_at_index<'a>(&'a self, path: &'a Path, index: u32, point_size: u16)
             -> Result<Font, String> {
         internal_load_font_at_index(path, index, point_size)
     }
 
+    /// Loads the font at the given face index of the file, with the given
+    /// size in points at the given horizontal and vertical resolutions in
+    /// DPI. Passing 0 as both resolutions uses SDL2_ttf's default of 72 DPI.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn load_font_with_options<'a>(&'a self, path: &'a Path, point_size: u16,
+            face_index: u32, hdpi: u32, vdpi: u32) -> Result<Font, String> {
+        internal_load_font_with_options(path, point_size, face_index, hdpi, vdpi)
+    }
+
     /// Loads a font from the given SDL2 rwops object with the given size in
     /// points.
     pub fn load_font_from_rwops<'a,'b>(&'a self, rwops: RWops<'b>, point_size: u16)
-            -> Result<Font<'b>, String> {
+            -> Result<Font<'b>, String> where 'a: 'b {
         let raw = unsafe {
+            let _lock = lock_library();
             ffi::TTF_OpenFontRW(rwops.raw(), 0, point_size as c_int)
         };
         if (raw as *mut ()).is_null() {
@@ -54,11 +125,22 @@ impl Sdl2TtfContext {
         }
     }
 
+    /// Reads a whole font from the given reader, e.g. a decompressing
+    /// reader over an archive, and loads it with the given size in points.
+    /// The font data is kept in memory for as long as the font lives.
+    pub fn load_font_from_reader<R>(&self, mut reader: R, point_size: u16)
+            -> Result<Font, String> where R: Read {
+        let mut data = Vec::new();
+        try!(reader.read_to_end(&mut data).map_err(|err| err.to_string()));
+        internal_load_font_from_data(data, point_size)
+    }
+
     /// Loads the font at the given index of the SDL2 rwops object with
     /// the given size in points.
     pub fn load_font_at_index_from_rwops<'a,'b>(&'a self, rwops: RWops<'b>, index: u32,
-            point_size: u16) -> Result<Font<'b>, String> {
+            point_size: u16) -> Result<Font<'b>, String> where 'a: 'b {
         let raw = unsafe {
+            let _lock = lock_library();
             ffi::TTF_OpenFontIndexRW(rwops.raw(), 0, point_size as c_int,
                 index as c_long)
         };
@@ -68,6 +150,119 @@ impl Sdl2TtfContext {
             Ok(internal_load_font_from_ll(raw, Some(rwops)))
         }
     }
+
+    /// Loads a font from the given SDL2 rwops object with the given size in
+    /// points at the given horizontal and vertical resolutions in DPI.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn load_font_from_rwops_dpi<'a,'b>(&'a self, rwops: RWops<'b>, point_size: u16,
+            hdpi: u32, vdpi: u32) -> Result<Font<'b>, String> where 'a: 'b {
+        let raw = unsafe {
+            let _lock = lock_library();
+            ffi::TTF_OpenFontDPIRW(rwops.raw(), 0, point_size as c_int,
+                hdpi as c_uint, vdpi as c_uint)
+        };
+        if (raw as *mut ()).is_null() {
+            Err(get_error())
+        } else {
+            Ok(internal_load_font_from_ll(raw, Some(rwops)))
+        }
+    }
+
+    /// Loads the font at the given index of the SDL2 rwops object with
+    /// the given size in points at the given horizontal and vertical
+    /// resolutions in DPI.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn load_font_at_index_from_rwops_dpi<'a,'b>(&'a self, rwops: RWops<'b>, index: u32,
+            point_size: u16, hdpi: u32, vdpi: u32) -> Result<Font<'b>, String> where 'a: 'b {
+        let raw = unsafe {
+            let _lock = lock_library();
+            ffi::TTF_OpenFontIndexDPIRW(rwops.raw(), 0, point_size as c_int,
+                index as c_long, hdpi as c_uint, vdpi as c_uint)
+        };
+        if (raw as *mut ()).is_null() {
+            Err(get_error())
+        } else {
+            Ok(internal_load_font_from_ll(raw, Some(rwops)))
+        }
+    }
+}
+
+#[cfg(feature = "global_context")]
+impl Sdl2TtfContext {
+    /// Returns a context living for the whole program, initializing the
+    /// truetype font API on first use.
+    /// As statics are never dropped, the library is not cleaned up when the
+    /// program exits, which is harmless on every mainstream platform.
+    ///
+    /// # Panics
+    /// Panics if the truetype font API fails to initialize.
+    pub fn global() -> &'static Sdl2TtfContext {
+        static CONTEXT: OnceLock<Sdl2TtfContext> = OnceLock::new();
+        CONTEXT.get_or_init(|| {
+            Sdl2TtfContext::init_or_get().expect("could not initialize SDL2_TTF")
+        })
+    }
+}
+
+/// An extension trait to load fonts straight out of an SDL2 rwops object,
+/// through the TTF context. `RWops` belongs to the `sdl2` crate, so these
+/// cannot be inherent methods; import the trait to use them.
+/// The fonts borrow the context as well as the rwops data, as SDL2_ttf frees
+/// every font when it is cleaned up.
+pub trait LoaderRWops<'a> {
+    /// Loads a font from this rwops object with the given size in points.
+    fn load_font<'c>(self, context: &'c Sdl2TtfContext, point_size: u16)
+        -> Result<Font<'c>, String> where 'a: 'c;
+
+    /// Loads the font at the given index of this rwops object, with the
+    /// given size in points.
+    fn load_font_at_index<'c>(self, context: &'c Sdl2TtfContext, index: u32, point_size: u16)
+        -> Result<Font<'c>, String> where 'a: 'c;
+
+    /// Loads a font from this rwops object with the given size in points at
+    /// the given horizontal and vertical resolutions in DPI.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    fn load_font_dpi<'c>(self, context: &'c Sdl2TtfContext, point_size: u16, hdpi: u32, vdpi: u32)
+        -> Result<Font<'c>, String> where 'a: 'c;
+
+    /// Loads the font at the given index of this rwops object, with the
+    /// given size in points at the given horizontal and vertical resolutions
+    /// in DPI.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    fn load_font_at_index_dpi<'c>(self, context: &'c Sdl2TtfContext, index: u32, point_size: u16,
+        hdpi: u32, vdpi: u32) -> Result<Font<'c>, String> where 'a: 'c;
+}
+
+impl<'a> LoaderRWops<'a> for RWops<'a> {
+    fn load_font<'c>(self, context: &'c Sdl2TtfContext, point_size: u16)
+            -> Result<Font<'c>, String> where 'a: 'c {
+        context.load_font_from_rwops(self, point_size)
+    }
+
+    fn load_font_at_index<'c>(self, context: &'c Sdl2TtfContext, index: u32, point_size: u16)
+            -> Result<Font<'c>, String> where 'a: 'c {
+        context.load_font_at_index_from_rwops(self, index, point_size)
+    }
+
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    fn load_font_dpi<'c>(self, context: &'c Sdl2TtfContext, point_size: u16, hdpi: u32, vdpi: u32)
+            -> Result<Font<'c>, String> where 'a: 'c {
+        context.load_font_from_rwops_dpi(self, point_size, hdpi, vdpi)
+    }
+
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    fn load_font_at_index_dpi<'c>(self, context: &'c Sdl2TtfContext, index: u32, point_size: u16,
+            hdpi: u32, vdpi: u32) -> Result<Font<'c>, String> where 'a: 'c {
+        context.load_font_at_index_from_rwops_dpi(self, index, point_size, hdpi, vdpi)
+    }
 }
 
 /// Returns the version of the dynamically linked `SDL_TTF` library
@@ -77,49 +272,68 @@ pub fn get_linked_version() -> Version {
     }
 }
 
+/// Returns the version of the dynamically linked `SDL_TTF` library as a
+/// `(major, minor, patch)` tuple, which unlike `Version` can be compared:
+/// `linked_version_tuple() >= (2, 20, 0)`.
+pub fn linked_version_tuple() -> (u8, u8, u8) {
+    let version = get_linked_version();
+    (version.major, version.minor, version.patch)
+}
+
+/// Returns whether the dynamically linked `SDL_TTF` library is at least the
+/// given version, to check at runtime whether newer features are available.
+pub fn requires_ttf_version(major: u8, minor: u8, patch: u8) -> bool {
+    linked_version_tuple() >= (major, minor, patch)
+}
+
+/// Sets whether UCS-2 text passed to the `*_unicode` functions is
+/// byte-swapped relative to the native byte order. A byte order mark at the
+/// start of the text overrides this setting.
+pub fn set_byte_swapped_unicode(swapped: bool) {
+    unsafe { ffi::TTF_ByteSwappedUNICODE(swapped as c_int) }
+}
+
 /// An error for when `sdl2_ttf` is attempted initialized twice
 /// Necessary for context management, unless we find a way to have a singleton
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum InitError {
     InitializationError(io::Error),
     AlreadyInitializedError,
 }
 
 impl error::Error for InitError {
-    fn description(&self) -> &str {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match *self {
             InitError::AlreadyInitializedError => {
-                "SDL2_TTF has already been initialized"
+                None
             },
             InitError::InitializationError(ref error) => {
-                error.description()
+                Some(error)
             },
         }
     }
+}
 
-    fn cause(&self) -> Option<&error::Error> {
+impl fmt::Display for InitError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match *self {
             InitError::AlreadyInitializedError => {
-                None
+                formatter.write_str("SDL2_TTF has already been initialized")
             },
             InitError::InitializationError(ref error) => {
-                Some(error)
+                write!(formatter, "SDL2_TTF failed to initialize: {}", error)
             },
         }
     }
 }
 
-impl fmt::Display for InitError {
-    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        formatter.write_str("SDL2_TTF has already been initialized")
-    }
-}
-
 /// Initializes the truetype font API and returns a context manager which will
 /// clean up the library once it goes out of scope.
 pub fn init() -> Result<Sdl2TtfContext, InitError> {
+    let _lock = lock_library();
     unsafe {
-        if ffi::TTF_WasInit() == 1 {
+        if ffi::TTF_WasInit() != 0 {
             Err(InitError::AlreadyInitializedError)
         } else if ffi::TTF_Init() == 0 {
             Ok(Sdl2TtfContext)
@@ -134,6 +348,6 @@ pub fn init() -> Result<Sdl2TtfContext, InitError> {
 /// Returns whether library has been initialized already.
 pub fn has_been_initialized() -> bool {
     unsafe {
-        ffi::TTF_WasInit() == 1
+        ffi::TTF_WasInit() != 0
     }
 }
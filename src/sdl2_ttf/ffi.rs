@@ -1,10 +1,13 @@
 use std::os::raw::{c_int, c_char, c_long, c_void};
+#[cfg(sdl2_ttf_version_gte_2_0_18)]
+use std::os::raw::c_uint;
 use sdl2_sys::surface::SDL_Surface;
 use sdl2_sys::pixels::SDL_Color;
 use sdl2_sys::rwops::SDL_RWops;
 use sdl2_sys::version::SDL_version;
 
 
+// Style flags as of SDL2_ttf 2.20, which added none since 2.0.12.
 pub const TTF_STYLE_NORMAL        : c_int = 0x00;
 pub const TTF_STYLE_BOLD          : c_int = 0x01;
 pub const TTF_STYLE_ITALIC        : c_int = 0x02;
@@ -16,6 +19,22 @@ pub const TTF_HINTING_LIGHT     : c_int = 1;
 pub const TTF_HINTING_MONO      : c_int = 2;
 pub const TTF_HINTING_NONE      : c_int = 3;
 
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+pub const TTF_DIRECTION_LTR : c_int = 0;
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+pub const TTF_DIRECTION_RTL : c_int = 1;
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+pub const TTF_DIRECTION_TTB : c_int = 2;
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+pub const TTF_DIRECTION_BTT : c_int = 3;
+
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+pub const TTF_WRAPPED_ALIGN_LEFT   : c_int = 0;
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+pub const TTF_WRAPPED_ALIGN_CENTER : c_int = 1;
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+pub const TTF_WRAPPED_ALIGN_RIGHT  : c_int = 2;
+
 pub type TTF_Font = c_void;
 extern "C" {
     pub fn TTF_Linked_Version() -> *const SDL_version;
@@ -24,16 +43,41 @@ extern "C" {
     pub fn TTF_OpenFont(file: *const c_char, ptsize: c_int) -> *const TTF_Font;
     pub fn TTF_OpenFontIndex(file: *const c_char, ptsize: c_int, index: c_long) ->
      *const TTF_Font;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_OpenFontIndexDPI(file: *const c_char, ptsize: c_int, index: c_long,
+                                hdpi: c_uint, vdpi: c_uint) -> *const TTF_Font;
     pub fn TTF_OpenFontRW(src: *const SDL_RWops, freesrc: c_int, ptsize: c_int)
      -> *const TTF_Font;
     pub fn TTF_OpenFontIndexRW(src: *const SDL_RWops, freesrc: c_int,
                                ptsize: c_int, index: c_long) -> *const TTF_Font;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_OpenFontDPIRW(src: *const SDL_RWops, freesrc: c_int, ptsize: c_int,
+                             hdpi: c_uint, vdpi: c_uint) -> *const TTF_Font;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_OpenFontIndexDPIRW(src: *const SDL_RWops, freesrc: c_int, ptsize: c_int,
+                                  index: c_long, hdpi: c_uint, vdpi: c_uint)
+     -> *const TTF_Font;
     pub fn TTF_GetFontStyle(font: *const TTF_Font) -> c_int;
     pub fn TTF_SetFontStyle(font: *const TTF_Font, style: c_int);
     pub fn TTF_GetFontOutline(font: *const TTF_Font) -> c_int;
     pub fn TTF_SetFontOutline(font: *const TTF_Font, outline: c_int);
     pub fn TTF_GetFontHinting(font: *const TTF_Font) -> c_int;
     pub fn TTF_SetFontHinting(font: *const TTF_Font, hinting: c_int);
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn TTF_GetFontWrappedAlign(font: *const TTF_Font) -> c_int;
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn TTF_SetFontWrappedAlign(font: *const TTF_Font, align: c_int);
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_GetFontSDF(font: *const TTF_Font) -> c_int;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_SetFontSDF(font: *const TTF_Font, on_off: c_int) -> c_int;
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn TTF_SetFontDirection(font: *const TTF_Font, direction: c_int) -> c_int;
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn TTF_SetFontScriptName(font: *const TTF_Font, script: *const c_char) -> c_int;
+    #[cfg(sdl2_ttf_version_gte_2_22_0)]
+    pub fn TTF_SetFontLanguage(font: *const TTF_Font, language_bcp47: *const c_char)
+     -> c_int;
     pub fn TTF_FontHeight(font: *const TTF_Font) -> c_int;
     pub fn TTF_FontAscent(font: *const TTF_Font) -> c_int;
     pub fn TTF_FontDescent(font: *const TTF_Font) -> c_int;
@@ -45,15 +89,24 @@ extern "C" {
     pub fn TTF_FontFaceFamilyName(font: *const TTF_Font) -> *const c_char;
     pub fn TTF_FontFaceStyleName(font: *const TTF_Font) -> *const c_char;
     pub fn TTF_GlyphIsProvided(font: *const TTF_Font, ch: u16) -> c_int;
-    pub fn TTF_GlyphMetrics(font: *const TTF_Font, ch: u16, minx: *const c_int,
-                            maxx: *const c_int, miny: *const c_int,
-                            maxy: *const c_int, advance: *const c_int) -> c_int;
-    pub fn TTF_SizeText(font: *const TTF_Font, text: *const c_char, w: *const c_int,
-                        h: *const c_int) -> c_int;
-    pub fn TTF_SizeUTF8(font: *const TTF_Font, text: *const c_char, w: *const c_int,
-                        h: *const c_int) -> c_int;
-    pub fn TTF_SizeUNICODE(font: *const TTF_Font, text: *const u16, w: *const c_int,
-                           h: *const c_int) -> c_int;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_GlyphIsProvided32(font: *const TTF_Font, ch: u32) -> c_int;
+    pub fn TTF_GlyphMetrics(font: *const TTF_Font, ch: u16, minx: *mut c_int,
+                            maxx: *mut c_int, miny: *mut c_int,
+                            maxy: *mut c_int, advance: *mut c_int) -> c_int;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_GlyphMetrics32(font: *const TTF_Font, ch: u32, minx: *mut c_int,
+                              maxx: *mut c_int, miny: *mut c_int,
+                              maxy: *mut c_int, advance: *mut c_int) -> c_int;
+    pub fn TTF_SizeText(font: *const TTF_Font, text: *const c_char, w: *mut c_int,
+                        h: *mut c_int) -> c_int;
+    pub fn TTF_SizeUTF8(font: *const TTF_Font, text: *const c_char, w: *mut c_int,
+                        h: *mut c_int) -> c_int;
+    pub fn TTF_SizeUNICODE(font: *const TTF_Font, text: *const u16, w: *mut c_int,
+                           h: *mut c_int) -> c_int;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_MeasureUTF8(font: *const TTF_Font, text: *const c_char, measure_width: c_int,
+                           extent: *mut c_int, count: *mut c_int) -> c_int;
     pub fn TTF_RenderText_Solid(font: *const TTF_Font, text: *const c_char,
                                 fg: SDL_Color) -> *mut SDL_Surface;
     pub fn TTF_RenderUTF8_Solid(font: *const TTF_Font, text: *const c_char,
@@ -62,6 +115,14 @@ extern "C" {
                                    fg: SDL_Color) -> *mut SDL_Surface;
     pub fn TTF_RenderGlyph_Solid(font: *const TTF_Font, ch: u16,
                                  fg: SDL_Color) -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_RenderText_Solid_Wrapped(font: *const TTF_Font, text: *const c_char,
+                                        fg: SDL_Color, wrapLength: u32)
+     -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_RenderUTF8_Solid_Wrapped(font: *const TTF_Font, text: *const c_char,
+                                        fg: SDL_Color, wrapLength: u32)
+     -> *mut SDL_Surface;
     pub fn TTF_RenderText_Shaded(font: *const TTF_Font, text: *const c_char,
                                  fg: SDL_Color, bg: SDL_Color) ->
      *mut SDL_Surface;
@@ -74,6 +135,14 @@ extern "C" {
     pub fn TTF_RenderGlyph_Shaded(font: *const TTF_Font, ch: u16,
                                   fg: SDL_Color, bg: SDL_Color) ->
      *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_RenderText_Shaded_Wrapped(font: *const TTF_Font, text: *const c_char,
+                                         fg: SDL_Color, bg: SDL_Color, wrapLength: u32)
+     -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_RenderUTF8_Shaded_Wrapped(font: *const TTF_Font, text: *const c_char,
+                                         fg: SDL_Color, bg: SDL_Color, wrapLength: u32)
+     -> *mut SDL_Surface;
     pub fn TTF_RenderText_Blended(font: *const TTF_Font, text: *const c_char,
                                   fg: SDL_Color) -> *mut SDL_Surface;
     pub fn TTF_RenderUTF8_Blended(font: *const TTF_Font, text: *const c_char,
@@ -89,12 +158,61 @@ extern "C" {
     pub fn TTF_RenderUNICODE_Blended_Wrapped(font: *const TTF_Font,
                                              text: *const u16, fg: SDL_Color,
                                              wrapLength: u32) ->
-     *const SDL_Surface;
+     *mut SDL_Surface;
     pub fn TTF_RenderGlyph_Blended(font: *const TTF_Font, ch: u16,
                                    fg: SDL_Color) -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_RenderUNICODE_Solid_Wrapped(font: *const TTF_Font, text: *const u16,
+                                           fg: SDL_Color, wrapLength: u32)
+     -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_RenderUNICODE_Shaded_Wrapped(font: *const TTF_Font, text: *const u16,
+                                            fg: SDL_Color, bg: SDL_Color, wrapLength: u32)
+     -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_RenderGlyph32_Solid(font: *const TTF_Font, ch: u32,
+                                   fg: SDL_Color) -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_RenderGlyph32_Shaded(font: *const TTF_Font, ch: u32,
+                                    fg: SDL_Color, bg: SDL_Color) -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_RenderGlyph32_Blended(font: *const TTF_Font, ch: u32,
+                                     fg: SDL_Color) -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn TTF_RenderText_LCD(font: *const TTF_Font, text: *const c_char,
+                              fg: SDL_Color, bg: SDL_Color) -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn TTF_RenderUTF8_LCD(font: *const TTF_Font, text: *const c_char,
+                              fg: SDL_Color, bg: SDL_Color) -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn TTF_RenderUNICODE_LCD(font: *const TTF_Font, text: *const u16,
+                                 fg: SDL_Color, bg: SDL_Color) -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn TTF_RenderText_LCD_Wrapped(font: *const TTF_Font, text: *const c_char,
+                                      fg: SDL_Color, bg: SDL_Color, wrapLength: u32)
+     -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn TTF_RenderUTF8_LCD_Wrapped(font: *const TTF_Font, text: *const c_char,
+                                      fg: SDL_Color, bg: SDL_Color, wrapLength: u32)
+     -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn TTF_RenderUNICODE_LCD_Wrapped(font: *const TTF_Font, text: *const u16,
+                                         fg: SDL_Color, bg: SDL_Color, wrapLength: u32)
+     -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn TTF_RenderGlyph_LCD(font: *const TTF_Font, ch: u16,
+                               fg: SDL_Color, bg: SDL_Color) -> *mut SDL_Surface;
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn TTF_RenderGlyph32_LCD(font: *const TTF_Font, ch: u32,
+                                 fg: SDL_Color, bg: SDL_Color) -> *mut SDL_Surface;
     pub fn TTF_CloseFont(font: *const TTF_Font);
     pub fn TTF_Quit();
     pub fn TTF_WasInit() -> c_int;
     pub fn TTF_GetFontKerningSize(font: *const TTF_Font, prev_index: c_int,
                                   index: c_int) -> c_int;
+    pub fn TTF_GetFontKerningSizeGlyphs(font: *const TTF_Font, previous_ch: u16,
+                                        ch: u16) -> c_int;
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn TTF_GetFontKerningSizeGlyphs32(font: *const TTF_Font, previous_ch: u32,
+                                          ch: u32) -> c_int;
 }
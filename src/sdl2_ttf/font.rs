@@ -1,17 +1,30 @@
 use std::ffi::{CString, CStr};
 use std::os::raw::{c_int, c_long};
+#[cfg(sdl2_ttf_version_gte_2_0_18)]
+use std::os::raw::c_uint;
 use std::path::Path;
 use std::error;
-use std::error::Error;
 use std::ffi::NulError;
 use std::fmt;
-use sdl2::surface::Surface;
+use std::cmp;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::slice;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use sdl2::surface::{Surface, SurfaceRef};
+use sdl2::rect::Rect;
 use sdl2_sys::surface::SDL_Surface;
 use sdl2::get_error;
 use sdl2::pixels;
-use sdl2::pixels::Color;
+use sdl2::pixels::{Color, Palette, PixelFormatEnum};
+use sdl2::render::BlendMode;
 use sdl2_sys::pixels::SDL_Color;
 use sdl2::rwops::RWops;
+use chain::render_with_fallbacks;
+use color::ColorExt;
+use context::{lock_library, Sdl2TtfContext};
+use wrap::WordWrapper;
 use ffi;
 
 /// Converts a rust-SDL2 color to its C ffi representation.
@@ -27,6 +40,8 @@ fn color_to_c_color(color: Color) -> SDL_Color {
 // Absolute paths are a workaround for https://github.com/rust-lang-nursery/bitflags/issues/39 .
 bitflags! {
     /// The styling of a font.
+    /// These are all the `TTF_STYLE_*` flags up to SDL2_ttf 2.20; signed
+    /// distance field rendering is a separate setting rather than a style.
     pub flags FontStyle: ::std::os::raw::c_int {
         const STYLE_NORMAL        = ::ffi::TTF_STYLE_NORMAL,
         const STYLE_BOLD          = ::ffi::TTF_STYLE_BOLD,
@@ -36,9 +51,45 @@ bitflags! {
     }
 }
 
+impl FontStyle {
+    /// Returns whether no style flag is set.
+    pub fn is_normal(&self) -> bool {
+        *self == STYLE_NORMAL
+    }
+
+    /// Returns whether the bold flag is set.
+    pub fn has_bold(&self) -> bool {
+        self.contains(STYLE_BOLD)
+    }
+
+    /// Returns whether the italic flag is set.
+    pub fn has_italic(&self) -> bool {
+        self.contains(STYLE_ITALIC)
+    }
+
+    /// Returns whether the underline flag is set.
+    pub fn has_underline(&self) -> bool {
+        self.contains(STYLE_UNDERLINE)
+    }
+
+    /// Returns whether the strikethrough flag is set.
+    pub fn has_strikethrough(&self) -> bool {
+        self.contains(STYLE_STRIKETHROUGH)
+    }
+}
+
+impl Default for FontStyle {
+    /// Returns `STYLE_NORMAL`, the style fonts are loaded with.
+    fn default() -> FontStyle {
+        STYLE_NORMAL
+    }
+}
+
 /// Information about the hinting of a font.
 /// See [wikipedia](https://en.wikipedia.org/wiki/Font_hinting)
+/// More hinting modes may be added along with newer SDL2_ttf releases.
 #[derive(Debug, PartialEq, Clone)]
+#[non_exhaustive]
 pub enum Hinting {
     Normal = ffi::TTF_HINTING_NORMAL as isize,
     Light  = ffi::TTF_HINTING_LIGHT  as isize,
@@ -46,6 +97,44 @@ pub enum Hinting {
     None   = ffi::TTF_HINTING_NONE   as isize
 }
 
+/// The direction in which text is laid out.
+/// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+/// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Direction {
+    LeftToRight = ffi::TTF_DIRECTION_LTR as isize,
+    RightToLeft = ffi::TTF_DIRECTION_RTL as isize,
+    TopToBottom = ffi::TTF_DIRECTION_TTB as isize,
+    BottomToTop = ffi::TTF_DIRECTION_BTT as isize,
+}
+
+/// How the lines of wrapped text are aligned.
+/// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+/// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum WrappedAlign {
+    Left   = ffi::TTF_WRAPPED_ALIGN_LEFT   as isize,
+    Center = ffi::TTF_WRAPPED_ALIGN_CENTER as isize,
+    Right  = ffi::TTF_WRAPPED_ALIGN_RIGHT  as isize,
+}
+
+/// The rendering mode used by the rendering helpers which composite several
+/// renderings into a single surface.
+/// See [the SDL2_TTF docs](https://www.libsdl.org/projects/SDL_ttf/docs/SDL_ttf.html#SEC42)
+/// for an explanation of the modes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum RenderMode {
+    Solid,
+    /// Shaded mode, against the given background color.
+    Shaded(Color),
+    Blended,
+}
+
 /// Information about a specific glyph (character) in a font face.
 #[derive(Debug, PartialEq, Clone)]
 pub struct GlyphMetrics {
@@ -56,33 +145,142 @@ pub struct GlyphMetrics {
     pub advance: i32
 }
 
+/// The dimensions of a piece of text rendered with a font, along with the
+/// font's vertical metrics.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TextMetrics {
+    pub width: u32,
+    pub height: u32,
+    /// The font's highest ascent (height above base).
+    pub ascent: i32,
+    /// The font's lowest descent (height below base). This is a negative
+    /// number.
+    pub descent: i32,
+}
+
+impl TextMetrics {
+    /// Returns the y-offset of the baseline from the top of the rendered text.
+    pub fn baseline_y(&self) -> i32 {
+        self.ascent
+    }
+}
+
+/// A text rendering in *solid* mode, kept as the 8-bit palettized surface
+/// SDL2_ttf renders it to: the background is the color key at index 0, and
+/// the text is drawn with the color at index 1.
+/// Recoloring it only updates its palette, which is much cheaper than
+/// rendering the text again, e.g. for HUD text changing color.
+pub struct SolidSurface {
+    surface: Surface<'static>,
+}
+
+impl SolidSurface {
+    /// Returns the palettized surface.
+    pub fn surface(&self) -> &Surface<'static> {
+        &self.surface
+    }
+
+    /// Returns the palettized surface, consuming this rendering.
+    pub fn into_surface(self) -> Surface<'static> {
+        self.surface
+    }
+
+    /// Returns the surface with the text drawn in the given color.
+    pub fn colorize<T>(mut self, fg: T) -> FontResult<Surface<'static>> where T: Into<Color> {
+        let fg = fg.into();
+        let (r, g, b) = fg.rgb();
+        // the background is hidden by the color key, but SDL2_ttf still sets
+        // it to the inverse of the foreground
+        let palette = try!(Palette::with_colors(&[Color::RGB(255 - r, 255 - g, 255 - b), fg]));
+        try!(self.surface.set_palette(&palette));
+        Ok(self.surface)
+    }
+}
+
+/// A text rendering made with signed distance field rendering enabled, for
+/// drawing scalable text with a shader.
+/// It is an ARGB8888 surface whose color channels are all the fill color,
+/// white, while the alpha channel holds the distance values: 128 at the
+/// glyph outlines, growing inside and shrinking outside of them. It should
+/// not be drawn directly as RGBA.
+/// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+/// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+#[cfg(sdl2_ttf_version_gte_2_0_18)]
+pub struct SdfSurface {
+    surface: Surface<'static>,
+}
+
+#[cfg(sdl2_ttf_version_gte_2_0_18)]
+impl SdfSurface {
+    /// Returns the underlying surface.
+    pub fn surface(&self) -> &Surface<'static> {
+        &self.surface
+    }
+
+    /// Returns the underlying surface, consuming this rendering.
+    pub fn into_surface(self) -> Surface<'static> {
+        self.surface
+    }
+
+    /// Returns the distance values as tightly packed rows of one byte per
+    /// pixel, ready to upload as a single-channel (R8) texture.
+    pub fn to_r8_pixels(&self) -> Vec<u8> {
+        let (width, height) = (self.surface.width() as usize, self.surface.height() as usize);
+        let pitch = self.surface.pitch() as usize;
+        let mut distances = Vec::with_capacity(width * height);
+        self.surface.with_lock(|pixels| {
+            for row in 0..height {
+                for column in 0..width {
+                    let (_, _, _, distance) = read_argb_pixel(&pixels[row * pitch + column * 4..]);
+                    distances.push(distance);
+                }
+            }
+        });
+        distances
+    }
+}
+
+/// How much of a piece of text fits within a given width, as returned by
+/// `Font::measure_str`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MeasureResult {
+    /// The width in pixels of the part of the text which fits.
+    pub pixel_extent: u32,
+    /// The number of characters which fit.
+    pub char_count: usize,
+    /// The length in bytes of the part of the text which fits.
+    pub byte_count: usize,
+}
+
+impl MeasureResult {
+    /// Splits the measured text into the part which fits and the rest.
+    pub fn split_at_measure<'t>(&self, text: &'t str) -> (&'t str, &'t str) {
+        text.split_at(self.byte_count)
+    }
+}
+
 /// The result of an `SDL2_TTF` font operation.
 pub type FontResult<T> = Result<T, FontError>;
 
 /// A font-related error.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum FontError {
     /// A Latin-1 encoded byte string is invalid.
     InvalidLatin1Text(NulError),
+    /// A string passed to SDL2_ttf contains a nul byte.
+    NulError(NulError),
     /// A SDL2-related error occured.
+    /// This is the catch-all for the raw error strings returned by SDL2,
+    /// which convert into it.
     SdlError(String),
 }
 
 impl error::Error for FontError {
-    fn description(&self) -> &str {
-        match *self {
-            FontError::InvalidLatin1Text(ref error) => {
-                error.description()
-            },
-            FontError::SdlError(ref message) => {
-                message
-            },
-        }
-    }
-
-    fn cause(&self) -> Option<&error::Error> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match *self {
-            FontError::InvalidLatin1Text(ref error) => {
+            FontError::InvalidLatin1Text(ref error) |
+            FontError::NulError(ref error) => {
                 Some(error)
             },
             FontError::SdlError(_) => {
@@ -96,7 +294,10 @@ impl fmt::Display for FontError {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match *self {
             FontError::InvalidLatin1Text(ref err) => {
-                write!(f, "Invalid Latin-1 bytes: {}", err.description())
+                write!(f, "Invalid Latin-1 bytes: {}", err)
+            },
+            FontError::NulError(ref err) => {
+                write!(f, "Invalid string: {}", err)
             },
             FontError::SdlError(ref msg) => {
                 write!(f, "SDL2 error: {}", msg)
@@ -106,6 +307,18 @@ impl fmt::Display for FontError {
     }
 }
 
+impl From<NulError> for FontError {
+    fn from(error: NulError) -> FontError {
+        FontError::NulError(error)
+    }
+}
+
+impl From<String> for FontError {
+    fn from(message: String) -> FontError {
+        FontError::SdlError(message)
+    }
+}
+
 /// A renderable piece of text in the UTF8 or Latin-1 format.
 enum RenderableText<'a> {
     Utf8(&'a str),
@@ -117,7 +330,7 @@ impl<'a> RenderableText<'a> {
     fn convert(&self) -> FontResult<CString> {
         match *self {
             RenderableText::Utf8(text) => {
-                Ok(CString::new(text).unwrap())
+                Ok(try!(CString::new(text)))
             },
             RenderableText::Latin1(bytes) => {
                 match CString::new(bytes) {
@@ -130,7 +343,7 @@ impl<'a> RenderableText<'a> {
                 }
             },
             RenderableText::Char(ref string) => {
-                Ok(CString::new(string.as_bytes()).unwrap())
+                Ok(try!(CString::new(string.as_bytes())))
             }
         }
     }
@@ -177,6 +390,32 @@ impl<'a> PartialRendering<'a> {
         convert_to_surface(raw)
     }
 
+    /// Renders the text in *solid* mode but wrapping the words if the width
+    /// exceeds the given maximum width.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    /// See [the SDL2_TTF docs](https://www.libsdl.org/projects/SDL_ttf/docs/SDL_ttf.html#SEC42)
+    /// for an explanation of the mode.
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn solid_wrapped<'b, T>(self, color: T, wrap_max_width: u32)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let source = try!(self.text.convert());
+        let color = color_to_c_color(color.into());
+        let raw = unsafe {
+            match self.text {
+                RenderableText::Utf8(_) | RenderableText::Char(_) => {
+                    ffi::TTF_RenderUTF8_Solid_Wrapped(self.font.raw(),
+                        source.as_ptr(), color, wrap_max_width)
+                },
+                RenderableText::Latin1(_) => {
+                    ffi::TTF_RenderText_Solid_Wrapped(self.font.raw(),
+                        source.as_ptr(), color, wrap_max_width)
+                },
+            }
+        };
+        convert_to_surface(raw)
+    }
+
     /// Renders the text in *shaded* mode.
     /// See [the SDL2_TTF docs](https://www.libsdl.org/projects/SDL_ttf/docs/SDL_ttf.html#SEC42)
     /// for an explanation.
@@ -200,6 +439,33 @@ impl<'a> PartialRendering<'a> {
         convert_to_surface(raw)
     }
 
+    /// Renders the text in *shaded* mode but wrapping the words if the width
+    /// exceeds the given maximum width.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    /// See [the SDL2_TTF docs](https://www.libsdl.org/projects/SDL_ttf/docs/SDL_ttf.html#SEC42)
+    /// for an explanation of the mode.
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn shaded_wrapped<'b, T>(self, color: T, background: T, wrap_max_width: u32)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let source = try!(self.text.convert());
+        let foreground = color_to_c_color(color.into());
+        let background = color_to_c_color(background.into());
+        let raw = unsafe {
+            match self.text {
+                RenderableText::Utf8(_) | RenderableText::Char(_) => {
+                    ffi::TTF_RenderUTF8_Shaded_Wrapped(self.font.raw(),
+                        source.as_ptr(), foreground, background, wrap_max_width)
+                },
+                RenderableText::Latin1(_) => {
+                    ffi::TTF_RenderText_Shaded_Wrapped(self.font.raw(),
+                        source.as_ptr(), foreground, background, wrap_max_width)
+                },
+            }
+        };
+        convert_to_surface(raw)
+    }
+
     /// Renders the text in *blended* mode.
     /// See [the SDL2_TTF docs](https://www.libsdl.org/projects/SDL_ttf/docs/SDL_ttf.html#SEC42)
     /// for an explanation.
@@ -244,6 +510,154 @@ impl<'a> PartialRendering<'a> {
         };
         convert_to_surface(raw)
     }
+
+    /// Renders the text in *LCD* mode, with subpixel antialiasing against
+    /// the given background color.
+    /// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+    /// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn lcd<'b, T>(self, color: T, background: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let source = try!(self.text.convert());
+        let foreground = color_to_c_color(color.into());
+        let background = color_to_c_color(background.into());
+        let raw = unsafe {
+            match self.text {
+                RenderableText::Utf8(_) | RenderableText::Char(_) => {
+                    ffi::TTF_RenderUTF8_LCD(self.font.raw(),
+                        source.as_ptr(), foreground, background)
+                },
+                RenderableText::Latin1(_) => {
+                    ffi::TTF_RenderText_LCD(self.font.raw(),
+                        source.as_ptr(), foreground, background)
+                },
+            }
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the text in *LCD* mode but wrapping the words if the width
+    /// exceeds the given maximum width.
+    /// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+    /// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn lcd_wrapped<'b, T>(self, color: T, background: T, wrap_max_width: u32)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let source = try!(self.text.convert());
+        let foreground = color_to_c_color(color.into());
+        let background = color_to_c_color(background.into());
+        let raw = unsafe {
+            match self.text {
+                RenderableText::Utf8(_) | RenderableText::Char(_) => {
+                    ffi::TTF_RenderUTF8_LCD_Wrapped(self.font.raw(),
+                        source.as_ptr(), foreground, background, wrap_max_width)
+                },
+                RenderableText::Latin1(_) => {
+                    ffi::TTF_RenderText_LCD_Wrapped(self.font.raw(),
+                        source.as_ptr(), foreground, background, wrap_max_width)
+                },
+            }
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the text in the given mode.
+    pub fn with_mode<'b, T>(self, color: T, mode: RenderMode)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        match mode {
+            RenderMode::Solid => self.solid(color),
+            RenderMode::Shaded(background) => self.shaded(color.into(), background),
+            RenderMode::Blended => self.blended(color),
+        }
+    }
+}
+
+/// Creates a blank surface to composite rendered layers onto. It is filled
+/// with the background color in shaded mode, and transparent otherwise.
+pub fn create_canvas<'b>(width: u32, height: u32, mode: RenderMode)
+        -> FontResult<Surface<'b>> {
+    let mut canvas = try!(Surface::new(cmp::max(width, 1), cmp::max(height, 1),
+        PixelFormatEnum::ARGB8888));
+    let background = match mode {
+        RenderMode::Shaded(background) => background,
+        RenderMode::Solid | RenderMode::Blended => Color::RGBA(0, 0, 0, 0),
+    };
+    try!(canvas.fill_rect(None, background));
+    Ok(canvas)
+}
+
+/// Copies a rendered layer onto a canvas at the given position.
+/// Layers are alpha blended onto what is already there if `blend` is set,
+/// and copied over it otherwise, which is what non-overlapping layers should
+/// use to keep their exact colors on a transparent canvas.
+/// Blending is done in software rather than by SDL2, whose blending leaves
+/// the canvas' alpha untouched and so darkens edges over transparent pixels.
+/// The canvas must be an ARGB8888 surface, as created by `create_canvas`.
+pub fn blit_layer(layer: &mut SurfaceRef, canvas: &mut SurfaceRef, x: i32, y: i32, blend: bool)
+        -> FontResult<()> {
+    if blend {
+        return composite_layer(layer, canvas, x, y);
+    }
+    try!(layer.set_blend_mode(BlendMode::None));
+    let target = Rect::new(x, y, layer.width(), layer.height());
+    try!(layer.blit(None, canvas, Some(target)));
+    Ok(())
+}
+
+/// Alpha blends a layer onto an ARGB8888 canvas at the given position,
+/// clipping it to the canvas.
+fn composite_layer(layer: &SurfaceRef, canvas: &mut SurfaceRef, x: i32, y: i32)
+        -> FontResult<()> {
+    let converted;
+    let layer = if layer.pixel_format_enum() == PixelFormatEnum::ARGB8888 {
+        layer
+    } else {
+        converted = try!(layer.convert(&canvas.pixel_format()));
+        &*converted
+    };
+    let (layer_width, layer_height) = (layer.width() as i32, layer.height() as i32);
+    let (canvas_width, canvas_height) = (canvas.width() as i32, canvas.height() as i32);
+    let (layer_pitch, canvas_pitch) = (layer.pitch() as usize, canvas.pitch() as usize);
+    layer.with_lock(|src| {
+        canvas.with_lock_mut(|dst| {
+            for row in cmp::max(-y, 0)..cmp::min(layer_height, canvas_height - y) {
+                for column in cmp::max(-x, 0)..cmp::min(layer_width, canvas_width - x) {
+                    let src_offset = row as usize * layer_pitch + column as usize * 4;
+                    let dst_offset = (row + y) as usize * canvas_pitch
+                        + (column + x) as usize * 4;
+                    let (r, g, b, a) = read_argb_pixel(&src[src_offset..]);
+                    let (dst_r, dst_g, dst_b, dst_a) = read_argb_pixel(&dst[dst_offset..]);
+                    let blended = Color::RGBA(dst_r, dst_g, dst_b, dst_a)
+                        .alpha_blend(Color::RGBA(r, g, b, a));
+                    write_argb_pixel(&mut dst[dst_offset..], blended.rgba());
+                }
+            }
+        });
+    });
+    Ok(())
+}
+
+/// Reads a pixel of an ARGB8888 surface, stored as a native-endian `u32`,
+/// as `(r, g, b, a)`.
+#[inline]
+pub fn read_argb_pixel(bytes: &[u8]) -> (u8, u8, u8, u8) {
+    let pixel = u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    ((pixel >> 16) as u8, (pixel >> 8) as u8, pixel as u8, (pixel >> 24) as u8)
+}
+
+/// Writes a pixel of an ARGB8888 surface, given as `(r, g, b, a)`.
+#[inline]
+pub fn write_argb_pixel(bytes: &mut [u8], (r, g, b, a): (u8, u8, u8, u8)) {
+    let pixel = (a as u32) << 24 | (r as u32) << 16 | (g as u32) << 8 | b as u32;
+    bytes[..4].copy_from_slice(&pixel.to_ne_bytes());
+}
+
+/// Copies the given UCS-2-encoded text and nul-terminates it for SDL2_ttf.
+fn ucs2_with_nul(text: &[u16]) -> Vec<u16> {
+    let mut source = Vec::with_capacity(text.len() + 1);
+    source.extend_from_slice(text);
+    source.push(0);
+    source
 }
 
 /// A loaded TTF font.
@@ -256,29 +670,129 @@ pub struct Font<'a> {
     // and Some(rwops) means that the RWops is handled by the Rust
     // side
     #[allow(dead_code)]
-    rwops:Option<RWops<'a>>
+    rwops:Option<RWops<'a>>,
+    // The font data the RWops reads from, when it is owned by the font.
+    // Fields are dropped in order, so this outlives the RWops.
+    #[allow(dead_code)]
+    data: Option<Vec<u8>>,
+    // whether the font is closed when dropped, which it is unless it has
+    // been wrapped by `from_raw` on behalf of C code
+    owned: bool,
+}
+
+/// A font loaded from data living for the whole program, typically embedded
+/// in the executable with `include_bytes!`.
+/// Like any font, it borrows the context: SDL2_ttf frees every font when it
+/// is cleaned up.
+pub type EmbeddedFont<'a> = Font<'a>;
+
+impl<'a> Font<'a> {
+    /// Loads a font from the given static data with the given size in
+    /// points.
+    pub fn from_bytes(context: &'a Sdl2TtfContext, data: &'static [u8], point_size: u16)
+            -> Result<EmbeddedFont<'a>, String> {
+        let rwops = try!(RWops::from_bytes(data));
+        context.load_font_from_rwops(rwops, point_size)
+    }
+}
+
+// SDL2_ttf fonts hold no thread-local state, so a font can be moved to and
+// used from another thread. All fonts share SDL2_ttf's FreeType library
+// though, which opening and closing a font changes without any locking: every
+// font is therefore opened, and closed on drop, with `lock_library` held. A
+// font is not `Sync`: rendering mutates the font's glyph cache, hence a single
+// font must never be used from several threads at once.
+unsafe impl<'a> Send for Font<'a> {}
+
+// Fonts are the same if they wrap the same SDL2_ttf font object, e.g. after
+// `from_raw`; fonts loaded separately are different even from the same file.
+impl<'a, 'b> PartialEq<Font<'b>> for Font<'a> {
+    fn eq(&self, other: &Font<'b>) -> bool {
+        self.raw == other.raw
+    }
 }
 
+impl<'a> Eq for Font<'a> {}
+
 impl<'a> Drop for Font<'a> {
     fn drop(&mut self) {
+        if !self.owned {
+            return;
+        }
+        let _lock = lock_library();
         unsafe {
             // avoid close font after quit()
-            if ffi::TTF_WasInit() == 1 {
+            if ffi::TTF_WasInit() != 0 {
                 ffi::TTF_CloseFont(self.raw);
             }
         }
     }
 }
 
+/// Converts the given path to a c-style string suitable for SDL2_ttf.
+/// On Unix, the path is passed on as raw bytes since `fopen` accepts any of
+/// them.
+#[cfg(unix)]
+fn path_to_cstring(path: &Path) -> Result<CString, String> {
+    use std::os::unix::ffi::OsStrExt;
+    CString::new(path.as_os_str().as_bytes()).map_err(|err| {
+        format!("Invalid path {}: {}", path.to_string_lossy(), err)
+    })
+}
+
+/// Converts the given path to a c-style string suitable for SDL2_ttf.
+/// Elsewhere, SDL2 expects paths to be encoded in UTF-8.
+#[cfg(not(unix))]
+fn path_to_cstring(path: &Path) -> Result<CString, String> {
+    match path.to_str() {
+        Some(path_str) => CString::new(path_str).map_err(|err| {
+            format!("Invalid path {}: {}", path_str, err)
+        }),
+        None => Err(format!("Path is not valid UTF-8: {}", path.to_string_lossy())),
+    }
+}
+
+/// Renders `text` with the font of the given file at the largest point size
+/// at which it fits within `max_width` by `max_height` pixels.
+/// This is the same as `Font::render_fitting`.
+pub fn render_fitting<'b, T>(context: &Sdl2TtfContext, path: &Path, text: &str, fg: T,
+        max_width: u32, max_height: u32, mode: RenderMode) -> FontResult<Surface<'b>>
+        where T: Into<Color> {
+    Font::render_fitting(context, path, text, fg, max_width, max_height, mode)
+}
+
 /// Internally used to load a font (for internal visibility).
 pub fn internal_load_font(path: &Path, ptsize: u16) -> Result<Font, String> {
+    let cstring = try!(path_to_cstring(path));
     unsafe {
-        let cstring = CString::new(path.to_str().unwrap()).unwrap();
-        let raw = ffi::TTF_OpenFont(cstring.as_ptr(), ptsize as c_int);
+        let raw = {
+            let _lock = lock_library();
+            ffi::TTF_OpenFont(cstring.as_ptr(), ptsize as c_int)
+        };
         if raw.is_null() {
             Err(get_error())
         } else {
-            Ok(Font { raw: raw, rwops: None })
+            Ok(Font { raw: raw, rwops: None, data: None, owned: true })
+        }
+    }
+}
+
+/// Internally used to load a font from data it takes ownership of.
+pub fn internal_load_font_from_data(data: Vec<u8>, ptsize: u16)
+        -> Result<Font<'static>, String> {
+    // The rwops reads from the heap buffer of `data`, which stays in place
+    // when `data` is moved into the font, and is freed after the rwops.
+    let bytes: &'static [u8] = unsafe { slice::from_raw_parts(data.as_ptr(), data.len()) };
+    let rwops = try!(RWops::from_bytes(bytes));
+    unsafe {
+        let raw = {
+            let _lock = lock_library();
+            ffi::TTF_OpenFontRW(rwops.raw(), 0, ptsize as c_int)
+        };
+        if (raw as *mut ()).is_null() {
+            Err(get_error())
+        } else {
+            Ok(Font { raw: raw, rwops: Some(rwops), data: Some(data), owned: true })
         }
     }
 }
@@ -286,21 +800,42 @@ pub fn internal_load_font(path: &Path, ptsize: u16) -> Result<Font, String> {
 /// Internally used to load a font (for internal visibility).
 pub fn internal_load_font_from_ll<'a>(raw: *const ffi::TTF_Font, rwops: Option<RWops<'a>>)
         -> Font<'a> {
-    Font { raw: raw, rwops: rwops }
+    Font { raw: raw, rwops: rwops, data: None, owned: true }
 }
 
 /// Internally used to load a font (for internal visibility).
 pub fn internal_load_font_at_index(path: &Path, index: u32, ptsize: u16)
         -> Result<Font, String> {
+    let cstring = try!(path_to_cstring(path));
+    unsafe {
+        let raw = {
+            let _lock = lock_library();
+            ffi::TTF_OpenFontIndex(cstring.as_ptr(),
+                ptsize as c_int, index as c_long)
+        };
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(Font { raw: raw, rwops: None, data: None, owned: true })
+        }
+    }
+}
+
+/// Internally used to load a font (for internal visibility).
+#[cfg(sdl2_ttf_version_gte_2_0_18)]
+pub fn internal_load_font_with_options(path: &Path, ptsize: u16, index: u32, hdpi: u32,
+        vdpi: u32) -> Result<Font, String> {
+    let cstring = try!(path_to_cstring(path));
     unsafe {
-        let cstring = CString::new(path.to_str().unwrap().as_bytes())
-            .unwrap();
-        let raw = ffi::TTF_OpenFontIndex(cstring.as_ptr(),
-            ptsize as c_int, index as c_long);
+        let raw = {
+            let _lock = lock_library();
+            ffi::TTF_OpenFontIndexDPI(cstring.as_ptr(),
+                ptsize as c_int, index as c_long, hdpi as c_uint, vdpi as c_uint)
+        };
         if raw.is_null() {
             Err(get_error())
         } else {
-            Ok(Font { raw: raw, rwops: None })
+            Ok(Font { raw: raw, rwops: None, data: None, owned: true })
         }
     }
 }
@@ -311,7 +846,41 @@ impl<'a> Font<'a> {
         self.raw
     }
 
+    /// Wraps a `TTF_Font*` obtained from C code, e.g. from another library
+    /// sharing the same SDL2_ttf.
+    /// If `owned` is true, the font is closed with `TTF_CloseFont` when the
+    /// returned `Font` is dropped. Otherwise the caller keeps the
+    /// responsibility of closing it, which must only happen once the `Font`
+    /// has been dropped.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be a valid, open font, which must not be used from
+    /// elsewhere while the `Font` renders with it.
+    ///
+    /// The lifetime `'a` is unbounded and chosen by the caller. The `Font`
+    /// must not outlive the SDL2_ttf initialization `raw` was opened under,
+    /// e.g. by borrowing the `Sdl2TtfContext`, as cleaning up SDL2_ttf frees
+    /// every font.
+    pub unsafe fn from_raw(raw: *mut ffi::TTF_Font, owned: bool) -> Font<'a> {
+        Font { raw: raw as *const ffi::TTF_Font, rwops: None, data: None, owned: owned }
+    }
+
+    /// Consumes the font without closing it and returns the underlying
+    /// `TTF_Font*`, e.g. to hand it over to C code, which then becomes
+    /// responsible for calling `TTF_CloseFont`.
+    /// A font loaded from an rwops object keeps reading from it, so that
+    /// rwops object and its data are leaked rather than freed along with the
+    /// `Font`.
+    pub fn into_raw(self) -> *mut ffi::TTF_Font {
+        let raw = self.raw;
+        mem::forget(self);
+        raw as *mut ffi::TTF_Font
+    }
+
     /// Starts specifying a rendering of the given UTF-8-encoded text.
+    /// For text known to be ASCII or Latin-1, `render_latin1` is faster as
+    /// it skips SDL2_ttf's UTF-8 decoding.
     pub fn render(&'a self, text: &'a str) -> PartialRendering<'a> {
         PartialRendering {
             text: RenderableText::Utf8(text),
@@ -320,6 +889,9 @@ impl<'a> Font<'a> {
     }
 
     /// Starts specifying a rendering of the given Latin-1-encoded text.
+    /// This skips SDL2_ttf's UTF-8 decoding, which makes it the fastest way
+    /// to render ASCII text: pass `text.as_bytes()` for a `&str` known to be
+    /// ASCII. See `benches/render_latin1.rs` for a comparison with `render`.
     pub fn render_latin1(&'a self, text: &'a [u8]) -> PartialRendering<'a> {
         PartialRendering {
             text: RenderableText::Latin1(text),
@@ -337,66 +909,1016 @@ impl<'a> Font<'a> {
         }
     }
 
-    /// Returns the width and height of the given text when rendered using this
-    /// font.
-    #[allow(unused_mut)]
-    pub fn size_of(&self, text: &str) -> FontResult<(u32, u32)> {
-        let c_string = try!(RenderableText::Utf8(text).convert());
-        let (res, size) = unsafe {
-            let mut w = 0; // mutated by C code
-            let mut h = 0; // mutated by C code
-            let ret = ffi::TTF_SizeUTF8(self.raw, c_string.as_ptr(), &w, &h);
-            (ret, (w as u32, h as u32))
-        };
-        if res == 0 {
-            Ok(size)
-        } else {
-            Err(FontError::SdlError(get_error()))
-        }
+    /// Renders the given UTF-8-encoded text in *solid* mode, keeping the
+    /// palettized surface so it can be colorized later on.
+    pub fn render_str_solid_fast(&self, text: &str) -> FontResult<SolidSurface> {
+        let surface = try!(self.render(text).solid(Color::RGB(255, 255, 255)));
+        Ok(SolidSurface { surface: surface })
     }
 
-    /// Returns the width and height of the given text when rendered using this
-    /// font.
-    #[allow(unused_mut)]
-    pub fn size_of_latin1(&self, text: &[u8])
-        -> FontResult<(u32, u32)> {
-        let c_string = try!(RenderableText::Latin1(text).convert());
-        let (res, size) = unsafe {
-            let mut w = 0; // mutated by C code
-            let mut h = 0; // mutated by C code
-            let ret = ffi::TTF_SizeText(self.raw, c_string.as_ptr(), &w, &h);
-            (ret, (w as u32, h as u32))
+    /// Renders the given UTF-8-encoded text in *solid* mode, with the
+    /// surface's alpha modulation set to `alpha` so it is blitted
+    /// semi-transparently.
+    /// This applies the same transparency to the whole surface: the glyphs
+    /// keep the hard edges of solid mode, unlike *blended* mode's per-pixel
+    /// alpha.
+    pub fn render_str_solid_with_alpha<'b, T>(&self, text: &str, fg: T, alpha: u8)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let mut surface = try!(self.render(text).solid(fg));
+        surface.set_alpha_mod(alpha);
+        Ok(surface)
+    }
+
+    /// Renders the given UCS-2-encoded text in *solid* mode.
+    /// The text does not need to be nul-terminated, and ends at its first
+    /// nul code unit if it contains any.
+    pub fn render_unicode_solid<'b, T>(&self, text: &[u16], fg: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let source = ucs2_with_nul(text);
+        let raw = unsafe {
+            ffi::TTF_RenderUNICODE_Solid(self.raw, source.as_ptr(), color_to_c_color(fg.into()))
         };
-        if res == 0 {
-            Ok(size)
-        } else {
-            Err(FontError::SdlError(get_error()))
-        }
+        convert_to_surface(raw)
     }
 
-    /// Returns the width and height of the given text when rendered using this
-    /// font.
-    pub fn size_of_char(&self, ch: char) -> FontResult<(u32, u32)> {
-        let mut s = String::new();
-        s.push(ch);
-        self.size_of(&s)
+    /// Renders the given UCS-2-encoded text in *shaded* mode.
+    /// The text does not need to be nul-terminated, and ends at its first
+    /// nul code unit if it contains any.
+    pub fn render_unicode_shaded<'b, T>(&self, text: &[u16], fg: T, background: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let source = ucs2_with_nul(text);
+        let raw = unsafe {
+            ffi::TTF_RenderUNICODE_Shaded(self.raw, source.as_ptr(),
+                color_to_c_color(fg.into()), color_to_c_color(background.into()))
+        };
+        convert_to_surface(raw)
     }
 
-    /// Returns the font's style flags.
-    pub fn get_style(&self) -> FontStyle {
-        unsafe {
-            let raw = ffi::TTF_GetFontStyle(self.raw);
-            FontStyle::from_bits_truncate(raw)
-        }
+    /// Renders the given UCS-2-encoded text in *blended* mode.
+    /// The text does not need to be nul-terminated, and ends at its first
+    /// nul code unit if it contains any.
+    pub fn render_unicode_blended<'b, T>(&self, text: &[u16], fg: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let source = ucs2_with_nul(text);
+        let raw = unsafe {
+            ffi::TTF_RenderUNICODE_Blended(self.raw, source.as_ptr(),
+                color_to_c_color(fg.into()))
+        };
+        convert_to_surface(raw)
     }
 
-    /// Sets the font's style flags.
-    pub fn set_style(&mut self, styles: FontStyle) {
-        unsafe {
+    /// Renders the given UCS-2-encoded text in *solid* mode, wrapping the
+    /// words if the width exceeds the given maximum width.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn render_unicode_solid_wrapped<'b, T>(&self, text: &[u16], fg: T, wrap_max_width: u32)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let source = ucs2_with_nul(text);
+        let raw = unsafe {
+            ffi::TTF_RenderUNICODE_Solid_Wrapped(self.raw, source.as_ptr(),
+                color_to_c_color(fg.into()), wrap_max_width)
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given UCS-2-encoded text in *shaded* mode, wrapping the
+    /// words if the width exceeds the given maximum width.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn render_unicode_shaded_wrapped<'b, T>(&self, text: &[u16], fg: T, background: T,
+            wrap_max_width: u32) -> FontResult<Surface<'b>> where T: Into<Color> {
+        let source = ucs2_with_nul(text);
+        let raw = unsafe {
+            ffi::TTF_RenderUNICODE_Shaded_Wrapped(self.raw, source.as_ptr(),
+                color_to_c_color(fg.into()), color_to_c_color(background.into()),
+                wrap_max_width)
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given UCS-2-encoded text in *blended* mode, wrapping the
+    /// words if the width exceeds the given maximum width.
+    pub fn render_unicode_blended_wrapped<'b, T>(&self, text: &[u16], fg: T,
+            wrap_max_width: u32) -> FontResult<Surface<'b>> where T: Into<Color> {
+        let source = ucs2_with_nul(text);
+        let raw = unsafe {
+            ffi::TTF_RenderUNICODE_Blended_Wrapped(self.raw, source.as_ptr(),
+                color_to_c_color(fg.into()), wrap_max_width)
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given UCS-2-encoded text in *LCD* mode.
+    /// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+    /// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn render_unicode_lcd<'b, T>(&self, text: &[u16], fg: T, background: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let source = ucs2_with_nul(text);
+        let raw = unsafe {
+            ffi::TTF_RenderUNICODE_LCD(self.raw, source.as_ptr(),
+                color_to_c_color(fg.into()), color_to_c_color(background.into()))
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given UCS-2-encoded text in *LCD* mode, wrapping the
+    /// words if the width exceeds the given maximum width.
+    /// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+    /// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn render_unicode_lcd_wrapped<'b, T>(&self, text: &[u16], fg: T, background: T,
+            wrap_max_width: u32) -> FontResult<Surface<'b>> where T: Into<Color> {
+        let source = ucs2_with_nul(text);
+        let raw = unsafe {
+            ffi::TTF_RenderUNICODE_LCD_Wrapped(self.raw, source.as_ptr(),
+                color_to_c_color(fg.into()), color_to_c_color(background.into()),
+                wrap_max_width)
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given UCS-2 code unit in *solid* mode.
+    /// Unlike `render_char`, this takes the code unit as is, which is how
+    /// SDL2_ttf identifies glyphs in UCS-2 text.
+    pub fn render_glyph_solid<'b, T>(&self, glyph: u16, fg: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let raw = unsafe {
+            ffi::TTF_RenderGlyph_Solid(self.raw, glyph, color_to_c_color(fg.into()))
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given UCS-2 code unit in *shaded* mode.
+    pub fn render_glyph_shaded<'b, T>(&self, glyph: u16, fg: T, background: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let raw = unsafe {
+            ffi::TTF_RenderGlyph_Shaded(self.raw, glyph, color_to_c_color(fg.into()),
+                color_to_c_color(background.into()))
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given UCS-2 code unit in *blended* mode.
+    pub fn render_glyph_blended<'b, T>(&self, glyph: u16, fg: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let raw = unsafe {
+            ffi::TTF_RenderGlyph_Blended(self.raw, glyph, color_to_c_color(fg.into()))
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given UCS-2 code unit in *LCD* mode.
+    /// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+    /// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn render_glyph_lcd<'b, T>(&self, glyph: u16, fg: T, background: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let raw = unsafe {
+            ffi::TTF_RenderGlyph_LCD(self.raw, glyph, color_to_c_color(fg.into()),
+                color_to_c_color(background.into()))
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given character in *solid* mode, including characters
+    /// outside of the Basic Multilingual Plane.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn render_glyph32_solid<'b, T>(&self, ch: char, fg: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let raw = unsafe {
+            ffi::TTF_RenderGlyph32_Solid(self.raw, ch as u32, color_to_c_color(fg.into()))
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given character in *shaded* mode, including characters
+    /// outside of the Basic Multilingual Plane.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn render_glyph32_shaded<'b, T>(&self, ch: char, fg: T, background: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let raw = unsafe {
+            ffi::TTF_RenderGlyph32_Shaded(self.raw, ch as u32, color_to_c_color(fg.into()),
+                color_to_c_color(background.into()))
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given character in *blended* mode, including characters
+    /// outside of the Basic Multilingual Plane.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn render_glyph32_blended<'b, T>(&self, ch: char, fg: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let raw = unsafe {
+            ffi::TTF_RenderGlyph32_Blended(self.raw, ch as u32, color_to_c_color(fg.into()))
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given character in *LCD* mode, including characters
+    /// outside of the Basic Multilingual Plane.
+    /// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+    /// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn render_glyph32_lcd<'b, T>(&self, ch: char, fg: T, background: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let raw = unsafe {
+            ffi::TTF_RenderGlyph32_LCD(self.raw, ch as u32, color_to_c_color(fg.into()),
+                color_to_c_color(background.into()))
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode and blits it
+    /// onto `dest` with its top-left corner at `(x, y)`.
+    /// This saves the caller from handling the intermediate surface, which
+    /// is freed as soon as it has been blitted.
+    pub fn render_str_blended_to<T>(&self, text: &str, fg: T, dest: &mut SurfaceRef,
+            x: i32, y: i32) -> FontResult<()> where T: Into<Color> {
+        let surface = try!(self.render(text).blended(fg));
+        let target = Rect::new(x, y, surface.width(), surface.height());
+        try!(surface.blit(None, dest, Some(target)));
+        Ok(())
+    }
+
+    /// Renders each of the given UTF-8-encoded texts in *blended* mode with
+    /// its own color, e.g. the items of a menu, and returns the surfaces in
+    /// the same order. Fails on the first text which cannot be rendered.
+    pub fn render_str_blended_batch<'b>(&self, texts: &[(&str, Color)])
+            -> FontResult<Vec<Surface<'b>>> {
+        let mut surfaces = Vec::with_capacity(texts.len());
+        for &(text, fg) in texts {
+            surfaces.push(try!(self.render(text).blended(fg)));
+        }
+        Ok(surfaces)
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode, and returns
+    /// its pixels as tightly packed RGBA bytes along with its width and
+    /// height, for use outside of SDL2's rendering.
+    pub fn render_str_blended_pixels<T>(&self, text: &str, fg: T)
+            -> FontResult<(Vec<u8>, u32, u32)> where T: Into<Color> {
+        let surface = try!(self.render(text).blended(fg));
+        let (width, height) = surface.size();
+        let pitch = surface.pitch() as usize;
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+        surface.with_lock(|bytes| {
+            for row in bytes.chunks(pitch).take(height as usize) {
+                for pixel in row[..width as usize * 4].chunks(4) {
+                    let (r, g, b, a) = read_argb_pixel(pixel);
+                    pixels.extend_from_slice(&[r, g, b, a]);
+                }
+            }
+        });
+        Ok((pixels, width, height))
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode and returns its
+    /// coverage as an 8-bit surface, whose palette maps each index to the
+    /// gray level of the same intensity. This is the text's alpha mask, e.g.
+    /// to be uploaded as a separate alpha texture.
+    pub fn render_str_grayscale<'b>(&self, text: &str) -> FontResult<Surface<'b>> {
+        let blended = try!(self.render(text).blended(Color::RGBA(255, 255, 255, 255)));
+        let (width, height) = blended.size();
+        let mut grayscale = try!(Surface::new(width, height, PixelFormatEnum::Index8));
+        let levels: Vec<Color> = (0..256).map(|level| {
+            Color::RGB(level as u8, level as u8, level as u8)
+        }).collect();
+        let palette = try!(Palette::with_colors(&levels));
+        try!(grayscale.set_palette(&palette));
+
+        let src_pitch = blended.pitch() as usize;
+        let dst_pitch = grayscale.pitch() as usize;
+        blended.with_lock(|src| {
+            grayscale.with_lock_mut(|dst| {
+                let rows = src.chunks(src_pitch).zip(dst.chunks_mut(dst_pitch));
+                for (src_row, dst_row) in rows.take(height as usize) {
+                    let pixels = src_row[..width as usize * 4].chunks(4);
+                    for (pixel, level) in pixels.zip(dst_row.iter_mut()) {
+                        *level = read_argb_pixel(pixel).3;
+                    }
+                }
+            });
+        });
+        Ok(grayscale)
+    }
+
+    /// Renders as many copies of `mask_char` as there are characters in the
+    /// given text, e.g. to display a password field.
+    /// Fails if `mask_char` is not provided by this font face.
+    pub fn render_masked<'b, T>(&self, text: &str, mask_char: char, fg: T, mode: RenderMode)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        if !self.is_glyph_provided32(mask_char) {
+            return Err(FontError::SdlError(
+                format!("Mask character {:?} is not provided by the font", mask_char)));
+        }
+        let masked: String = text.chars().map(|_| mask_char).collect();
+        self.render(&masked).with_mode(fg, mode)
+    }
+
+    /// Returns the width in pixels of a tab stop `columns` spaces wide.
+    pub fn tab_size_in_pixels(&self, columns: u32) -> FontResult<u32> {
+        let (space_width, _) = try!(self.size_of(" "));
+        Ok(space_width * columns)
+    }
+
+    /// Renders the given UTF-8-encoded text after replacing its tabs with
+    /// spaces, up to the next multiple of `tab_width` pixels. SDL2_ttf
+    /// itself does not expand tabs.
+    pub fn render_str_with_tabs<'b, T>(&self, text: &str, tab_width: u32, fg: T,
+            mode: RenderMode) -> FontResult<Surface<'b>> where T: Into<Color> {
+        let (space_width, _) = try!(self.size_of(" "));
+        let space_width = cmp::max(space_width, 1);
+        let tab_width = cmp::max(tab_width, 1);
+        let mut expanded = String::with_capacity(text.len());
+        // where the current line starts in `expanded`
+        let mut line_start = 0;
+        for ch in text.chars() {
+            match ch {
+                '\t' => {
+                    let (width, _) = try!(self.size_of(&expanded[line_start..]));
+                    let next_stop = (width / tab_width + 1) * tab_width;
+                    let spaces = cmp::max((next_stop - width).div_ceil(space_width), 1);
+                    for _ in 0..spaces {
+                        expanded.push(' ');
+                    }
+                },
+                '\n' => {
+                    expanded.push(ch);
+                    line_start = expanded.len();
+                },
+                _ => expanded.push(ch),
+            }
+        }
+        self.render(&expanded).with_mode(fg, mode)
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode over a drop
+    /// shadow of it, offset by the given number of pixels.
+    /// The surface is large enough to hold both the text and its shadow.
+    pub fn render_str_blended_shadow<'b, T>(&self, text: &str, fg: T, shadow_color: T,
+            offset_x: i32, offset_y: i32) -> FontResult<Surface<'b>> where T: Into<Color> {
+        let mut shadow = try!(self.render(text).blended(shadow_color));
+        let mut surface = try!(self.render(text).blended(fg));
+        let (width, height) = surface.size();
+        let mut canvas = try!(create_canvas(width + offset_x.unsigned_abs(),
+            height + offset_y.unsigned_abs(), RenderMode::Blended));
+        let (x, y) = (cmp::max(-offset_x, 0), cmp::max(-offset_y, 0));
+        try!(blit_layer(&mut shadow, &mut canvas, x + offset_x, y + offset_y, false));
+        try!(blit_layer(&mut surface, &mut canvas, x, y, true));
+        Ok(canvas)
+    }
+
+    /// Renders the given UTF-8-encoded text as a signed distance field.
+    /// Signed distance field rendering must have been enabled with
+    /// `set_sdf`, otherwise the distance values are plain antialiasing.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn render_str_sdf(&self, text: &str) -> FontResult<SdfSurface> {
+        let surface = try!(self.render(text).blended(Color::RGB(255, 255, 255)));
+        Ok(SdfSurface { surface: surface })
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode with a vertical
+    /// gradient, going from `top_color` at its top row to `bottom_color` at
+    /// its bottom row, e.g. for title screens.
+    /// The text is rendered in white, then multiplied with the gradient.
+    pub fn render_str_blended_gradient<'b, T>(&self, text: &str, top_color: T, bottom_color: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let (top, bottom) = (top_color.into().rgba(), bottom_color.into().rgba());
+        let mut surface = try!(self.render(text).blended(Color::RGB(255, 255, 255)));
+        let (width, height) = surface.size();
+        let mut canvas = try!(create_canvas(width, height, RenderMode::Blended));
+        try!(blit_layer(&mut surface, &mut canvas, 0, 0, false));
+        let (width, height) = (width as usize, height as usize);
+        let pitch = canvas.pitch() as usize;
+        let lerp = |from: u8, to: u8, t: f32| from as f32 + (to as f32 - from as f32) * t;
+        let multiply = |channel: u8, factor: f32| (channel as f32 * factor / 255.0).round() as u8;
+        canvas.with_lock_mut(|pixels| {
+            for row in 0..height {
+                let t = if height > 1 { row as f32 / (height - 1) as f32 } else { 0.0 };
+                let (r, g, b, a) = (lerp(top.0, bottom.0, t), lerp(top.1, bottom.1, t),
+                    lerp(top.2, bottom.2, t), lerp(top.3, bottom.3, t));
+                for column in 0..width {
+                    let offset = row * pitch + column * 4;
+                    let (pixel_r, pixel_g, pixel_b, pixel_a) = read_argb_pixel(&pixels[offset..]);
+                    write_argb_pixel(&mut pixels[offset..], (multiply(pixel_r, r),
+                        multiply(pixel_g, g), multiply(pixel_b, b), multiply(pixel_a, a)));
+                }
+            }
+        });
+        Ok(canvas)
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode, mirrored
+    /// horizontally and/or vertically, e.g. for reflection effects.
+    /// SDL2 has no surface flipping, so the pixels are moved in software.
+    pub fn render_str_blended_flipped<'b, T>(&self, text: &str, fg: T,
+            flip_horizontal: bool, flip_vertical: bool)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let mut surface = try!(self.render(text).blended(fg));
+        let (width, height) = surface.size();
+        let mut canvas = try!(create_canvas(width, height, RenderMode::Blended));
+        try!(blit_layer(&mut surface, &mut canvas, 0, 0, false));
+        let (width, height) = (width as usize, height as usize);
+        let pitch = canvas.pitch() as usize;
+        canvas.with_lock_mut(|pixels| {
+            if flip_vertical {
+                for row in 0..height / 2 {
+                    let (top, bottom) = pixels.split_at_mut((height - 1 - row) * pitch);
+                    top[row * pitch..row * pitch + width * 4]
+                        .swap_with_slice(&mut bottom[..width * 4]);
+                }
+            }
+            if flip_horizontal {
+                for row in 0..height {
+                    let line = &mut pixels[row * pitch..row * pitch + width * 4];
+                    for column in 0..width / 2 {
+                        let mirrored = (width - 1 - column) * 4;
+                        for byte in 0..4 {
+                            line.swap(column * 4 + byte, mirrored + byte);
+                        }
+                    }
+                }
+            }
+        });
+        Ok(canvas)
+    }
+
+    /// Renders the given UTF-8-encoded text with this font, rendering the
+    /// characters it does not provide with the first of `fallbacks` which
+    /// does, as a `FontChain` would without taking ownership of the fonts.
+    /// Characters provided by none of the fonts are replaced by U+FFFD (�)
+    /// if one of them provides it.
+    pub fn render_str_with_fallbacks<'b, T>(&self, text: &str, fallbacks: &[&Font], fg: T,
+            mode: RenderMode) -> FontResult<Surface<'b>> where T: Into<Color> {
+        let mut fonts: Vec<&Font> = Vec::with_capacity(fallbacks.len() + 1);
+        fonts.push(self);
+        fonts.extend_from_slice(fallbacks);
+        let provided = |ch| fonts.iter().any(|font| font.is_glyph_provided32(ch));
+        let text: Cow<str> = if provided('\u{FFFD}') && !text.chars().all(&provided) {
+            Cow::Owned(text.chars()
+                .map(|ch| if provided(ch) { ch } else { '\u{FFFD}' })
+                .collect())
+        } else {
+            Cow::Borrowed(text)
+        };
+        render_with_fallbacks(&fonts, &text, fg.into(), mode)
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode over a
+    /// rectangle filled with `bg`, e.g. to highlight search results.
+    /// The rectangle extends `padding` pixels beyond the text on every side.
+    pub fn render_str_blended_highlighted<'b, T>(&self, text: &str, fg: T, bg: T,
+            padding: u32) -> FontResult<Surface<'b>> where T: Into<Color> {
+        let mut surface = try!(self.render(text).blended(fg));
+        let (width, height) = surface.size();
+        let mut canvas = try!(create_canvas(width + 2 * padding, height + 2 * padding,
+            RenderMode::Shaded(bg.into())));
+        try!(blit_layer(&mut surface, &mut canvas, padding as i32, padding as i32, true));
+        Ok(canvas)
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode, surrounded
+    /// by an outline `outline_width` pixels thick.
+    /// The outline is rendered by temporarily changing the font's outline
+    /// width, which is restored afterwards even if rendering fails; as this
+    /// takes the font mutably, no other rendering can observe the change.
+    /// The fill is then blended centered over the outline.
+    pub fn render_str_blended_outlined<'b, T>(&mut self, text: &str, fg: T, outline_color: T,
+            outline_width: u16) -> FontResult<Surface<'b>> where T: Into<Color> {
+        let previous_width = self.get_outline_width();
+        self.set_outline_width(outline_width);
+        let outline = self.render(text).blended(outline_color);
+        self.set_outline_width(0);
+        let fill = self.render(text).blended(fg);
+        self.set_outline_width(previous_width);
+
+        let mut outline = try!(outline);
+        let mut fill = try!(fill);
+        let (width, height) = outline.size();
+        let mut canvas = try!(create_canvas(width, height, RenderMode::Blended));
+        try!(blit_layer(&mut outline, &mut canvas, 0, 0, false));
+        let offset = outline_width as i32;
+        try!(blit_layer(&mut fill, &mut canvas, offset, offset, true));
+        Ok(canvas)
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode without
+    /// kerning, e.g. to lay out monospaced columns, then restores the
+    /// font's kerning setting. As this takes the font mutably, no other
+    /// rendering can observe the temporary setting.
+    pub fn render_str_blended_no_kerning<'b, T>(&mut self, text: &str, fg: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let previous_kerning = self.get_kerning();
+        self.set_kerning(false);
+        let surface = self.render(text).blended(fg);
+        self.set_kerning(previous_kerning);
+        surface
+    }
+
+    /// Renders the given UTF-8-encoded text wrapped to `target_width` pixels
+    /// and justified, by spreading out the words of each line so it spans
+    /// the whole width. The last line of each paragraph, and lines made of a
+    /// single word, are left-aligned instead.
+    pub fn render_str_justified<'b, T>(&self, text: &str, fg: T, target_width: u32,
+            mode: RenderMode) -> FontResult<Surface<'b>> where T: Into<Color> {
+        let fg = fg.into();
+        let wrapper = WordWrapper::new(self, target_width);
+        // each line, along with whether it should be justified
+        let mut lines = Vec::new();
+        for paragraph in text.split('\n') {
+            let paragraph_lines = try!(wrapper.wrap(paragraph));
+            let last = paragraph_lines.len() - 1;
+            for (index, line) in paragraph_lines.into_iter().enumerate() {
+                lines.push((line, index != last));
+            }
+        }
+
+        let skip = self.recommended_line_spacing();
+        let height = skip * (lines.len() as i32 - 1) + self.height();
+        let mut canvas = try!(create_canvas(target_width, cmp::max(height, 0) as u32, mode));
+        for (index, &(line, justify)) in lines.iter().enumerate() {
+            let y = skip * index as i32;
+            let words: Vec<&str> = line.split_whitespace().collect();
+            if words.is_empty() {
+                continue;
+            }
+            if !justify || words.len() == 1 {
+                let mut surface = try!(self.render(line).with_mode(fg, mode));
+                try!(blit_layer(&mut surface, &mut canvas, 0, y, false));
+                continue;
+            }
+            let mut widths = Vec::with_capacity(words.len());
+            for word in &words {
+                widths.push(try!(self.size_of(word)).0 as i32);
+            }
+            let free_space = cmp::max(target_width as i32 - widths.iter().sum::<i32>(), 0);
+            let gaps = words.len() as i32 - 1;
+            let mut x = 0;
+            for (gap, (word, width)) in words.iter().zip(widths).enumerate() {
+                let mut surface = try!(self.render(word).with_mode(fg, mode));
+                try!(blit_layer(&mut surface, &mut canvas, x, y, false));
+                // the first gaps get the pixels which can't be evenly spread
+                let extra = if (gap as i32) < free_space % gaps { 1 } else { 0 };
+                x += width + free_space / gaps + extra;
+            }
+        }
+        Ok(canvas)
+    }
+
+    /// Renders the given lines of UTF-8-encoded text below each other into a
+    /// single surface, as wide as the widest line.
+    /// Lines are `line_spacing` pixels further apart than the font's
+    /// recommended line spacing.
+    pub fn render_lines<'b, T>(&self, lines: &[&str], fg: T, mode: RenderMode,
+            line_spacing: i32) -> FontResult<Surface<'b>> where T: Into<Color> {
+        if lines.is_empty() {
+            return Err(FontError::SdlError("Text has zero width".to_owned()));
+        }
+        let fg = fg.into();
+        let mut width = 0;
+        for line in lines {
+            let (line_width, _) = try!(self.size_of(line));
+            width = cmp::max(width, line_width);
+        }
+        let advance = self.recommended_line_spacing() + line_spacing;
+        let height = advance * (lines.len() as i32 - 1) + self.height();
+
+        let mut canvas = try!(create_canvas(width, cmp::max(height, 0) as u32, mode));
+        for (index, line) in lines.iter().enumerate() {
+            // SDL2_ttf refuses to render empty text
+            if line.is_empty() {
+                continue;
+            }
+            let mut surface = try!(self.render(line).with_mode(fg, mode));
+            try!(blit_layer(&mut surface, &mut canvas, 0, advance * index as i32, false));
+        }
+        Ok(canvas)
+    }
+
+    /// Renders the given UTF-8-encoded text in *solid* mode, breaking lines
+    /// only at its newlines, unlike SDL2_ttf's own wrapping.
+    /// The lines are stacked as with `render_lines` into an ARGB8888
+    /// surface, rather than the palettized surface of a single rendering.
+    pub fn render_str_solid_lines<'b, T>(&self, text: &str, fg: T)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        let lines: Vec<&str> = text.split('\n').map(|line| line.trim_end_matches('\r')).collect();
+        self.render_lines(&lines, fg, RenderMode::Solid, 0)
+    }
+
+    /// Renders each of the given characters with its own color, next to each
+    /// other, e.g. for syntax highlighting. Characters are placed according
+    /// to their advance, so they are not kerned.
+    /// The surface is the font's height tall and as wide as the sum of the
+    /// characters' advances.
+    pub fn render_char_sequence<'b>(&self, chars: &[(char, Color)], mode: RenderMode)
+            -> FontResult<Surface<'b>> {
+        self.render_chars_spaced(chars, mode, 0)
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode one character
+    /// at a time, adding `extra_advance` pixels between characters, as
+    /// SDL2_ttf has no letter spacing of its own. A negative `extra_advance`
+    /// tightens the text, a positive one loosens it.
+    /// Characters are placed according to their advance, so they are not
+    /// kerned.
+    pub fn render_str_blended_letter_spaced<'b, T>(&self, text: &str, fg: T,
+            extra_advance: i32) -> FontResult<Surface<'b>> where T: Into<Color> {
+        let fg = fg.into();
+        let chars: Vec<(char, Color)> = text.chars().map(|ch| (ch, fg)).collect();
+        self.render_chars_spaced(&chars, RenderMode::Blended, extra_advance)
+    }
+
+    /// Renders runs of text, each in its own color, next to each other.
+    /// This is cheaper than `render_char_sequence` when neighbouring
+    /// characters share a color, and keeps kerning within each run.
+    pub fn render_colored_runs<'b>(&self, runs: &[(&str, Color)], mode: RenderMode)
+            -> FontResult<Surface<'b>> {
+        let mut widths = Vec::with_capacity(runs.len());
+        for &(text, _) in runs {
+            let (width, _) = try!(self.size_of(text));
+            widths.push(width);
+        }
+        let width = widths.iter().sum::<u32>();
+        let mut canvas = try!(create_canvas(width, cmp::max(self.height(), 0) as u32, mode));
+        let mut x = 0;
+        for (&(text, color), width) in runs.iter().zip(widths) {
+            // SDL2_ttf refuses to render zero width text
+            if !text.is_empty() {
+                let mut surface = try!(self.render(text).with_mode(color, mode));
+                try!(blit_layer(&mut surface, &mut canvas, x, 0, true));
+            }
+            x += width as i32;
+        }
+        Ok(canvas)
+    }
+
+    /// Renders the given colored characters next to each other, with
+    /// `extra_advance` more pixels than their advance between them.
+    fn render_chars_spaced<'b>(&self, chars: &[(char, Color)], mode: RenderMode,
+            extra_advance: i32) -> FontResult<Surface<'b>> {
+        let advances: Vec<i32> = chars.iter().map(|&(ch, _)| {
+            self.glyph_metrics_of(ch).map_or(0, |metrics| metrics.advance)
+        }).collect();
+        let spacing = extra_advance * cmp::max(chars.len() as i32 - 1, 0);
+        let width = advances.iter().sum::<i32>() + spacing;
+        let height = self.height();
+        let mut canvas = try!(create_canvas(cmp::max(width, 0) as u32,
+            cmp::max(height, 0) as u32, mode));
+        let mut x = 0;
+        for (&(ch, color), advance) in chars.iter().zip(advances) {
+            let mut surface = try!(self.render_char(ch).with_mode(color, mode));
+            // neighbouring glyphs may overlap, e.g. in italics
+            try!(blit_layer(&mut surface, &mut canvas, x, 0, true));
+            x += advance + extra_advance;
+        }
+        Ok(canvas)
+    }
+
+    /// Returns the width and height of the given text when rendered using this
+    /// font.
+    /// Text containing a nul byte cannot be passed to SDL2_ttf, and fails
+    /// with `FontError::NulError` rather than panicking.
+    pub fn size_of(&self, text: &str) -> FontResult<(u32, u32)> {
+        let c_string = try!(RenderableText::Utf8(text).convert());
+        let (res, size) = unsafe {
+            let mut w = 0; // mutated by C code
+            let mut h = 0; // mutated by C code
+            let ret = ffi::TTF_SizeUTF8(self.raw, c_string.as_ptr(), &mut w, &mut h);
+            (ret, (w as u32, h as u32))
+        };
+        if res == 0 {
+            Ok(size)
+        } else {
+            Err(FontError::SdlError(get_error()))
+        }
+    }
+
+    /// Returns the width and height of the given UTF-8-encoded text with
+    /// newlines, as laid out by `render_lines` and `render_str_solid_lines`
+    /// without extra line spacing: the width of its widest line, and the
+    /// height of its lines, spaced by the font's recommended line spacing.
+    pub fn measure_str_multiline(&self, text: &str) -> FontResult<(u32, u32)> {
+        let mut width = 0;
+        let mut line_count = 0;
+        for line in text.split('\n') {
+            let (line_width, _) = try!(self.size_of(line.trim_end_matches('\r')));
+            width = cmp::max(width, line_width);
+            line_count += 1;
+        }
+        let height = self.recommended_line_spacing() * (line_count - 1) + self.height();
+        Ok((width, cmp::max(height, 0) as u32))
+    }
+
+    /// Returns the width and height of the given text when rendered using this
+    /// font.
+    pub fn size_of_latin1(&self, text: &[u8])
+        -> FontResult<(u32, u32)> {
+        let c_string = try!(RenderableText::Latin1(text).convert());
+        let (res, size) = unsafe {
+            let mut w = 0; // mutated by C code
+            let mut h = 0; // mutated by C code
+            let ret = ffi::TTF_SizeText(self.raw, c_string.as_ptr(), &mut w, &mut h);
+            (ret, (w as u32, h as u32))
+        };
+        if res == 0 {
+            Ok(size)
+        } else {
+            Err(FontError::SdlError(get_error()))
+        }
+    }
+
+    /// Returns the width and height of the given text when rendered using this
+    /// font.
+    pub fn size_of_char(&self, ch: char) -> FontResult<(u32, u32)> {
+        // encodes the character on the stack rather than in a String
+        let mut buffer = [0; 4];
+        self.size_of(ch.encode_utf8(&mut buffer))
+    }
+
+    /// Returns the size in pixels of the given point size at the given
+    /// resolution in DPI, a point being 1/72 of an inch.
+    /// This is the em size of the font, which its `height` usually exceeds
+    /// as it also spans the ascenders and descenders of the font face.
+    pub fn point_size_to_pixels(point_size: u16, dpi: u16) -> f32 {
+        point_size as f32 * dpi as f32 / 72.0
+    }
+
+    /// Returns how much of the given UTF-8-encoded text fits within
+    /// `max_width` pixels when rendered using this font.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn measure_str(&self, text: &str, max_width: u32) -> FontResult<MeasureResult> {
+        let c_string = try!(RenderableText::Utf8(text).convert());
+        let mut extent = 0; // mutated by C code
+        let mut count = 0; // mutated by C code
+        let ret = unsafe {
+            ffi::TTF_MeasureUTF8(self.raw, c_string.as_ptr(), max_width as c_int,
+                &mut extent, &mut count)
+        };
+        if ret != 0 {
+            return Err(FontError::SdlError(get_error()));
+        }
+        let char_count = count as usize;
+        let byte_count = text.char_indices().nth(char_count)
+            .map_or(text.len(), |(offset, _)| offset);
+        Ok(MeasureResult {
+            pixel_extent: extent as u32,
+            char_count: char_count,
+            byte_count: byte_count,
+        })
+    }
+
+    /// Returns the largest point size, up to 256, at which the font of the
+    /// given file renders `text` within `max_width` by `max_height` pixels,
+    /// e.g. to fit a label into a button.
+    /// This loads the font once per size tried, so the result is better
+    /// computed once than on every frame. Fails if the text does not even fit
+    /// at a size of 1 point.
+    pub fn best_fit_size(context: &Sdl2TtfContext, path: &Path, text: &str, max_width: u32,
+            max_height: u32) -> FontResult<u16> {
+        let fits = |point_size| -> FontResult<bool> {
+            let font = try!(context.load_font(path, point_size));
+            let (width, height) = try!(font.size_of(text));
+            Ok(width <= max_width && height <= max_height)
+        };
+        if !try!(fits(1)) {
+            return Err(FontError::SdlError(format!(
+                "Text does not fit within {}x{} pixels at any size", max_width, max_height)));
+        }
+        // the largest size known to fit, and the smallest known not to
+        let mut low = 1;
+        let mut high = 257;
+        while high - low > 1 {
+            let middle = low + (high - low) / 2;
+            if try!(fits(middle)) {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        Ok(low)
+    }
+
+    /// Renders `text` with the font of the given file at the largest point
+    /// size at which it fits within `max_width` by `max_height` pixels, as
+    /// found by `best_fit_size`.
+    pub fn render_fitting<'b, T>(context: &Sdl2TtfContext, path: &Path, text: &str, fg: T,
+            max_width: u32, max_height: u32, mode: RenderMode) -> FontResult<Surface<'b>>
+            where T: Into<Color> {
+        let point_size = try!(Font::best_fit_size(context, path, text, max_width, max_height));
+        let font = try!(context.load_font(path, point_size));
+        font.render(text).with_mode(fg, mode)
+    }
+
+    /// Returns the width and height of the given UCS-2-encoded text when
+    /// rendered using this font.
+    /// Like with the `render_unicode_*` functions, the text does not need to
+    /// be nul-terminated.
+    pub fn size_of_unicode(&self, text: &[u16]) -> FontResult<(u32, u32)> {
+        let source = ucs2_with_nul(text);
+        let (res, size) = unsafe {
+            let mut w = 0; // mutated by C code
+            let mut h = 0; // mutated by C code
+            let ret = ffi::TTF_SizeUNICODE(self.raw, source.as_ptr(), &mut w, &mut h);
+            (ret, (w as u32, h as u32))
+        };
+        if res == 0 {
+            Ok(size)
+        } else {
+            Err(FontError::SdlError(get_error()))
+        }
+    }
+
+    /// Returns the dimensions of the given text when rendered using this
+    /// font, along with the font's ascent and descent.
+    pub fn measure(&self, text: &str) -> FontResult<TextMetrics> {
+        let (width, height) = try!(self.size_of(text));
+        Ok(TextMetrics {
+            width: width,
+            height: height,
+            ascent: self.ascent(),
+            descent: self.descent(),
+        })
+    }
+
+    /// Returns the x-offset in pixels at which a text cursor placed before
+    /// the character at `char_index` should be drawn.
+    /// An index past the last character places the cursor at the end of the
+    /// text.
+    pub fn cursor_x_at_char_index(&self, text: &str, char_index: usize)
+            -> FontResult<i32> {
+        let offset = text.char_indices().nth(char_index)
+            .map_or(text.len(), |(offset, _)| offset);
+        if offset == 0 {
+            return Ok(0);
+        }
+        let (width, _) = try!(self.size_of(&text[..offset]));
+        Ok(width as i32)
+    }
+
+    /// Returns the index of the character boundary closest to the x-offset
+    /// `x` in pixels, i.e. where a text cursor should go when clicking at
+    /// `x`. The result ranges from 0 to the number of characters in `text`.
+    pub fn char_index_at_x(&self, text: &str, x: i32) -> FontResult<usize> {
+        if x <= 0 {
+            return Ok(0);
+        }
+        let mut boundaries: Vec<usize> = text.char_indices()
+            .map(|(offset, _)| offset).collect();
+        boundaries.push(text.len());
+
+        // binary search for the first boundary at or after `x`
+        let (mut low, mut high) = (0, boundaries.len() - 1);
+        while low < high {
+            let middle = (low + high) / 2;
+            let (width, _) = try!(self.size_of(&text[..boundaries[middle]]));
+            if (width as i32) < x {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        if low == 0 {
+            return Ok(0);
+        }
+        let (after, _) = try!(self.size_of(&text[..boundaries[low]]));
+        let before = if low == 1 {
+            0
+        } else {
+            try!(self.size_of(&text[..boundaries[low - 1]])).0
+        };
+        if x - (before as i32) <= (after as i32) - x {
+            Ok(low - 1)
+        } else {
+            Ok(low)
+        }
+    }
+
+    /// Returns the rectangle, relative to the rendered text, covering the
+    /// characters from `start_char` up to (excluding) `end_char`.
+    /// Its height is the font's height, so it can be used to draw selection
+    /// highlights.
+    pub fn selection_rect(&self, text: &str, start_char: usize, end_char: usize)
+            -> FontResult<Rect> {
+        let (start_char, end_char) = if start_char <= end_char {
+            (start_char, end_char)
+        } else {
+            (end_char, start_char)
+        };
+        let start_x = try!(self.cursor_x_at_char_index(text, start_char));
+        let end_x = try!(self.cursor_x_at_char_index(text, end_char));
+        // an empty selection still yields a 1 pixel wide rect, see `Rect::new`
+        let width = cmp::max(end_x - start_x, 0) as u32;
+        Ok(Rect::new(start_x, 0, width, self.height() as u32))
+    }
+
+    /// Returns the given text if it fits within `max_width` pixels, and
+    /// otherwise its longest prefix which still fits when followed by an
+    /// ellipsis ("…"), with the ellipsis appended.
+    /// If not even the ellipsis fits, an empty string is returned.
+    pub fn ellipsize_str<'t>(&self, text: &'t str, max_width: u32)
+            -> FontResult<Cow<'t, str>> {
+        const ELLIPSIS: &str = "\u{2026}";
+        let (width, _) = try!(self.size_of(text));
+        if width <= max_width {
+            return Ok(Cow::Borrowed(text));
+        }
+        let boundaries: Vec<usize> = text.char_indices()
+            .map(|(offset, _)| offset).collect();
+
+        // binary search for the number of characters to keep
+        let (mut low, mut high) = (0, boundaries.len() - 1);
+        while low < high {
+            let middle = (low + high).div_ceil(2);
+            let candidate = format!("{}{}", &text[..boundaries[middle]], ELLIPSIS);
+            let (width, _) = try!(self.size_of(&candidate));
+            if width <= max_width {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+        let prefix = text[..boundaries[low]].trim_end();
+        if prefix.is_empty() {
+            let (width, _) = try!(self.size_of(ELLIPSIS));
+            if width > max_width {
+                return Ok(Cow::Borrowed(""));
+            }
+        }
+        Ok(Cow::Owned(format!("{}{}", prefix, ELLIPSIS)))
+    }
+
+    /// Renders in *blended* mode the longest prefix of the given
+    /// UTF-8-encoded text which fits within `max_width` pixels, and returns
+    /// it along with the number of characters rendered. Unlike
+    /// `ellipsize_str`, the text is cut cleanly at a character boundary, as
+    /// text input fields do.
+    /// If not even the first character fits, a blank surface is returned.
+    pub fn render_str_blended_clipped<'b, T>(&self, text: &str, fg: T, max_width: u32)
+            -> FontResult<(Surface<'b>, usize)> where T: Into<Color> {
+        let mut boundaries: Vec<usize> = text.char_indices()
+            .map(|(offset, _)| offset).collect();
+        boundaries.push(text.len());
+
+        // binary search for the number of characters to keep
+        let (mut low, mut high) = (0, boundaries.len() - 1);
+        while low < high {
+            let middle = (low + high).div_ceil(2);
+            let (width, _) = try!(self.size_of(&text[..boundaries[middle]]));
+            if width <= max_width {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+        if low == 0 {
+            let height = cmp::max(self.height(), 0) as u32;
+            return Ok((try!(create_canvas(0, height, RenderMode::Blended)), 0));
+        }
+        let surface = try!(self.render(&text[..boundaries[low]]).blended(fg));
+        Ok((surface, low))
+    }
+
+    /// Returns the font's style flags.
+    pub fn get_style(&self) -> FontStyle {
+        unsafe {
+            let raw = ffi::TTF_GetFontStyle(self.raw);
+            FontStyle::from_bits_truncate(raw)
+        }
+    }
+
+    /// Sets the font's style flags.
+    pub fn set_style(&mut self, styles: FontStyle) {
+        unsafe {
             ffi::TTF_SetFontStyle(self.raw, styles.bits())
         }
     }
 
+    /// Sets the font's style flags until the returned guard is dropped,
+    /// which restores the previous ones, e.g. to render a single bold title.
+    /// Meanwhile, the font is used through the guard, which dereferences to
+    /// it.
+    pub fn apply_style_temporarily<'f>(&'f mut self, style: FontStyle) -> StyleGuard<'f, 'a> {
+        let previous = self.get_style();
+        self.set_style(style);
+        StyleGuard {
+            font: self,
+            previous: previous,
+        }
+    }
+
     /// Returns the width of the font's outline.
     pub fn get_outline_width(&self) -> u16 {
         unsafe {
@@ -430,6 +1952,58 @@ impl<'a> Font<'a> {
         }
     }
 
+    /// Returns how the lines of text rendered with this font in a
+    /// *wrapped* mode are aligned.
+    /// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+    /// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn get_wrapped_align(&self) -> WrappedAlign {
+        unsafe {
+            match ffi::TTF_GetFontWrappedAlign(self.raw) {
+                ffi::TTF_WRAPPED_ALIGN_CENTER => WrappedAlign::Center,
+                ffi::TTF_WRAPPED_ALIGN_RIGHT  => WrappedAlign::Right,
+                ffi::TTF_WRAPPED_ALIGN_LEFT | _ => WrappedAlign::Left
+            }
+        }
+    }
+
+    /// Sets how the lines of text rendered with this font in a *wrapped*
+    /// mode are aligned, which defaults to the left.
+    /// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+    /// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn set_wrapped_align(&mut self, align: WrappedAlign) {
+        unsafe {
+            ffi::TTF_SetFontWrappedAlign(self.raw, align as c_int)
+        }
+    }
+
+    /// Returns whether the font renders signed distance fields.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn get_sdf(&self) -> bool {
+        unsafe {
+            ffi::TTF_GetFontSDF(self.raw) != 0
+        }
+    }
+
+    /// Sets whether the font renders signed distance fields instead of
+    /// antialiased glyphs, see `render_str_sdf`.
+    /// Requires SDL2_ttf ≥ 2.0.18, detected by pkg-config or forced with the
+    /// `ttf-2-0-18` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn set_sdf(&mut self, sdf: bool) -> FontResult<()> {
+        let ret = unsafe {
+            ffi::TTF_SetFontSDF(self.raw, sdf as c_int)
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(FontError::SdlError(get_error()))
+        }
+    }
+
     /// Returns whether the font is kerning.
     pub fn get_kerning(&self) -> bool {
         unsafe {
@@ -444,6 +2018,138 @@ impl<'a> Font<'a> {
         }
     }
 
+    /// Sets the language of the text rendered with this font, as a BCP 47
+    /// code such as "sr-Latn". This selects the right glyph variants for
+    /// characters whose forms differ between locales. An empty string resets
+    /// the font to its default language.
+    /// Requires SDL2_ttf ≥ 2.22.0, detected by pkg-config or forced with the
+    /// `ttf-2-22-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    /// SDL2_ttf must also be built with HarfBuzz.
+    #[cfg(sdl2_ttf_version_gte_2_22_0)]
+    pub fn set_language(&mut self, language: &str) -> FontResult<()> {
+        let c_language = try!(CString::new(language));
+        let ret = unsafe {
+            ffi::TTF_SetFontLanguage(self.raw, c_language.as_ptr())
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(FontError::SdlError(get_error()))
+        }
+    }
+
+    /// Sets the direction in which the text rendered with this font is laid
+    /// out, which defaults to left to right.
+    /// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+    /// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    /// SDL2_ttf must also be built with HarfBuzz.
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn set_direction(&mut self, direction: Direction) -> FontResult<()> {
+        let ret = unsafe {
+            ffi::TTF_SetFontDirection(self.raw, direction as c_int)
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(FontError::SdlError(get_error()))
+        }
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode, laid out in
+    /// the given direction as with `set_direction`, then resets the font to
+    /// laying out text from left to right, as SDL2_ttf cannot tell the
+    /// direction set before.
+    /// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+    /// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    /// SDL2_ttf must also be built with HarfBuzz.
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn render_str_blended_with_direction<'b, T>(&mut self, text: &str, fg: T,
+            direction: Direction) -> FontResult<Surface<'b>> where T: Into<Color> {
+        try!(self.set_direction(direction));
+        let surface = self.render(text).blended(fg);
+        try!(self.set_direction(Direction::LeftToRight));
+        surface
+    }
+
+    /// Sets the script used to shape the text rendered with this font, as a
+    /// four-letter ISO 15924 code such as "Arab". "Zzzz", the code for an
+    /// unknown script, resets the font to guessing the script from the text.
+    /// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+    /// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    /// SDL2_ttf must also be built with HarfBuzz.
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn set_script(&mut self, script: &str) -> FontResult<()> {
+        let c_script = try!(CString::new(script));
+        let ret = unsafe {
+            ffi::TTF_SetFontScriptName(self.raw, c_script.as_ptr())
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(FontError::SdlError(get_error()))
+        }
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode, shaped with
+    /// the given script as with `set_script`, then resets the font's script.
+    /// SDL2_ttf cannot tell the script set before, so the font is always
+    /// reset to guessing the script from the text.
+    /// Requires SDL2_ttf ≥ 2.20.0, detected by pkg-config or forced with the
+    /// `ttf-2-20-0` feature; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    /// SDL2_ttf must also be built with HarfBuzz.
+    #[cfg(sdl2_ttf_version_gte_2_20_0)]
+    pub fn render_str_blended_with_script<'b, T>(&mut self, text: &str, fg: T, script: &str)
+            -> FontResult<Surface<'b>> where T: Into<Color> {
+        try!(self.set_script(script));
+        let surface = self.render(text).blended(fg);
+        try!(self.set_script("Zzzz"));
+        surface
+    }
+
+    /// Returns the kerning adjustment in pixels between the two given
+    /// characters, when `ch` directly follows `previous`.
+    /// Characters outside of the Basic Multilingual Plane are only supported
+    /// with SDL2_ttf ≥ 2.0.18, and have no kerning otherwise; see
+    /// [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn kerning_size_glyphs32(&self, previous: char, ch: char) -> i32 {
+        unsafe {
+            ffi::TTF_GetFontKerningSizeGlyphs32(self.raw, previous as u32, ch as u32) as i32
+        }
+    }
+
+    /// Returns the kerning adjustment in pixels between the two given
+    /// characters, when `ch` directly follows `previous`.
+    /// Characters outside of the Basic Multilingual Plane are only supported
+    /// with SDL2_ttf ≥ 2.0.18, and have no kerning otherwise; see
+    /// [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(not(sdl2_ttf_version_gte_2_0_18))]
+    pub fn kerning_size_glyphs32(&self, previous: char, ch: char) -> i32 {
+        if (previous as u32) > 0xFFFF || (ch as u32) > 0xFFFF {
+            return 0;
+        }
+        unsafe {
+            ffi::TTF_GetFontKerningSizeGlyphs(self.raw, previous as u16, ch as u16) as i32
+        }
+    }
+
+    /// Returns all the non-zero kerning adjustments between pairs of
+    /// characters of the given set, keyed by `(previous, next)` character.
+    /// This queries every ordered pair of characters, so it takes quadratic
+    /// time in the size of the set: compute it once and cache the result.
+    pub fn kerning_pairs(&self, charset: &[char]) -> HashMap<(char, char), i32> {
+        let mut pairs = HashMap::new();
+        for &previous in charset {
+            for &ch in charset {
+                let kerning = self.kerning_size_glyphs32(previous, ch);
+                if kerning != 0 {
+                    pairs.insert((previous, ch), kerning);
+                }
+            }
+        }
+        pairs
+    }
+
     pub fn height(&self) -> i32 {
         //! Get font maximum total height.
         unsafe {
@@ -524,16 +2230,126 @@ impl<'a> Font<'a> {
         }
     }
 
+    /// Returns whether the given character is provided by this font face.
+    pub fn is_glyph_provided(&self, ch: char) -> bool {
+        self.find_glyph(ch).is_some()
+    }
+
+    /// Returns whether the given character is provided by this font face,
+    /// including characters outside of the Basic Multilingual Plane.
+    /// Those are only supported with SDL2_ttf ≥ 2.0.18, and are never provided
+    /// otherwise; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn is_glyph_provided32(&self, ch: char) -> bool {
+        unsafe {
+            ffi::TTF_GlyphIsProvided32(self.raw, ch as u32) != 0
+        }
+    }
+
+    /// Returns whether the given character is provided by this font face,
+    /// including characters outside of the Basic Multilingual Plane.
+    /// Those are only supported with SDL2_ttf ≥ 2.0.18, and are never provided
+    /// otherwise; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(not(sdl2_ttf_version_gte_2_0_18))]
+    pub fn is_glyph_provided32(&self, ch: char) -> bool {
+        (ch as u32) <= 0xFFFF && self.is_glyph_provided(ch)
+    }
+
+    /// Returns whether this font face provides at least 90% of the
+    /// characters from `block_start` to `block_end` inclusive, e.g. to pick
+    /// a fallback font for a Unicode block it does not cover.
+    /// Large ranges are checked on an evenly spaced sample of characters.
+    pub fn has_unicode_support(&self, block_start: char, block_end: char) -> bool {
+        const SAMPLE_SIZE: u32 = 256;
+        let (start, end) = (block_start as u32, block_end as u32);
+        if end < start {
+            return false;
+        }
+        let step = cmp::max((end - start + 1) / SAMPLE_SIZE, 1);
+        let mut sampled = 0;
+        let mut provided = 0;
+        let mut code = start;
+        while code <= end {
+            // skips the surrogate range, which holds no characters
+            if let Some(ch) = ::std::char::from_u32(code) {
+                sampled += 1;
+                if self.is_glyph_provided32(ch) {
+                    provided += 1;
+                }
+            }
+            code += step;
+        }
+        sampled > 0 && provided * 10 >= sampled * 9
+    }
+
+    /// Returns the fraction of the given characters which are provided by
+    /// this font face, from 0 to 1, e.g. to pick the font covering a
+    /// document best. An empty set of characters is fully covered.
+    pub fn coverage_ratio(&self, chars: &[char]) -> f32 {
+        if chars.is_empty() {
+            return 1.0;
+        }
+        let provided = chars.iter().filter(|&&ch| self.is_glyph_provided32(ch)).count();
+        provided as f32 / chars.len() as f32
+    }
+
+    /// Returns the characters of the given text which this font face does
+    /// not provide, each once and in the order they first appear, e.g. to
+    /// tell whether and for what a fallback font is needed.
+    pub fn glyphs_missing_from_str(&self, text: &str) -> Vec<char> {
+        let mut missing = Vec::new();
+        for ch in text.chars() {
+            if !missing.contains(&ch) && !self.is_glyph_provided32(ch) {
+                missing.push(ch);
+            }
+        }
+        missing
+    }
+
+    /// Returns whether this font face seems to have color glyphs, as color
+    /// emoji fonts using COLR or CBDT tables do.
+    /// This is a heuristic: it renders U+1F600 (😀) in white and checks for
+    /// pixels which are not gray. It can therefore not detect fonts that
+    /// lack this emoji, nor fonts whose color glyphs the linked SDL2_ttf
+    /// renders in one color, which older versions do.
+    pub fn has_color_glyphs(&self) -> bool {
+        const EMOJI: char = '\u{1F600}';
+        if !self.is_glyph_provided32(EMOJI) {
+            return false;
+        }
+        let mut surface = match self.render_char(EMOJI).blended(Color::RGB(255, 255, 255)) {
+            Ok(surface) => surface,
+            Err(_) => return false,
+        };
+        let (width, height) = surface.size();
+        let mut canvas = match create_canvas(width, height, RenderMode::Blended) {
+            Ok(canvas) => canvas,
+            Err(_) => return false,
+        };
+        if blit_layer(&mut surface, &mut canvas, 0, 0, false).is_err() {
+            return false;
+        }
+        let pitch = canvas.pitch() as usize;
+        canvas.with_lock(|pixels| {
+            (0..height as usize).any(|row| {
+                (0..width as usize).any(|column| {
+                    let (r, g, b, a) = read_argb_pixel(&pixels[row * pitch + column * 4..]);
+                    a > 0 && (r != g || g != b)
+                })
+            })
+        })
+    }
+
     /// Returns the glyph metrics of the given character in this font face.
     pub fn find_glyph_metrics(&self, ch: char) -> Option<GlyphMetrics> {
-        let minx = 0;
-        let maxx = 0;
-        let miny = 0;
-        let maxy = 0;
-        let advance = 0;
+        let mut minx = 0; // mutated by C code
+        let mut maxx = 0; // mutated by C code
+        let mut miny = 0; // mutated by C code
+        let mut maxy = 0; // mutated by C code
+        let mut advance = 0; // mutated by C code
         let ret = unsafe {
             ffi::TTF_GlyphMetrics(
-                self.raw, ch as u16, &minx, &maxx, &miny, &maxy, &advance
+                self.raw, ch as u16, &mut minx, &mut maxx, &mut miny, &mut maxy, &mut advance
             )
         };
         if ret == 0 {
@@ -545,4 +2361,173 @@ impl<'a> Font<'a> {
             None
         }
     }
+
+    /// Returns the glyph metrics of the given character in this font face,
+    /// including characters outside of the Basic Multilingual Plane.
+    /// Those are only supported with SDL2_ttf ≥ 2.0.18, and have no metrics
+    /// otherwise; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(sdl2_ttf_version_gte_2_0_18)]
+    pub fn find_glyph_metrics32(&self, ch: char) -> Option<GlyphMetrics> {
+        let mut minx = 0; // mutated by C code
+        let mut maxx = 0; // mutated by C code
+        let mut miny = 0; // mutated by C code
+        let mut maxy = 0; // mutated by C code
+        let mut advance = 0; // mutated by C code
+        let ret = unsafe {
+            ffi::TTF_GlyphMetrics32(
+                self.raw, ch as u32, &mut minx, &mut maxx, &mut miny, &mut maxy, &mut advance
+            )
+        };
+        if ret == 0 {
+            Some(GlyphMetrics {
+                minx: minx as i32, maxx: maxx as i32, miny: miny as i32,
+                maxy: maxy as i32, advance: advance as i32
+            } )
+        } else {
+            None
+        }
+    }
+
+    /// Returns the glyph metrics of the given character in this font face,
+    /// including characters outside of the Basic Multilingual Plane.
+    /// Those are only supported with SDL2_ttf ≥ 2.0.18, and have no metrics
+    /// otherwise; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    #[cfg(not(sdl2_ttf_version_gte_2_0_18))]
+    pub fn find_glyph_metrics32(&self, ch: char) -> Option<GlyphMetrics> {
+        if (ch as u32) > 0xFFFF {
+            None
+        } else {
+            self.find_glyph_metrics(ch)
+        }
+    }
+
+    /// Returns the glyph metrics of the given character, only going through
+    /// the 32-bit SDL2_ttf API for characters outside of the Basic
+    /// Multilingual Plane.
+    fn glyph_metrics_of(&self, ch: char) -> Option<GlyphMetrics> {
+        if (ch as u32) > 0xFFFF {
+            self.find_glyph_metrics32(ch)
+        } else {
+            self.find_glyph_metrics(ch)
+        }
+    }
+
+    /// Returns the glyph metrics of every character of the given text, in
+    /// order. Characters not provided by this font face yield `None`.
+    pub fn glyph_metrics_for_str(&self, text: &str) -> Vec<Option<GlyphMetrics>> {
+        text.chars().map(|ch| self.glyph_metrics_of(ch)).collect()
+    }
+
+    /// Returns the advance width of every character of the given text, in
+    /// order. Characters not provided by this font face have an advance of 0.
+    pub fn advance_widths_for_str(&self, text: &str) -> Vec<i32> {
+        text.chars().map(|ch| {
+            self.glyph_metrics_of(ch).map_or(0, |metrics| metrics.advance)
+        }).collect()
+    }
+
+    /// Returns an iterator over all the characters of the Basic Multilingual
+    /// Plane (U+0000 to U+FFFF) provided by this font face, along with their
+    /// metrics.
+    pub fn glyphs<'f>(&'f self) -> GlyphIterator<'f, 'a> {
+        GlyphIterator {
+            font: self,
+            next: 0,
+            end: 0xFFFF,
+            wide: false,
+        }
+    }
+
+    /// Returns an iterator over all the Unicode characters provided by this
+    /// font face, along with their metrics.
+    /// Before SDL2_ttf 2.0.18, this only yields the characters of the Basic
+    /// Multilingual Plane, like `glyphs`; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    pub fn glyphs32<'f>(&'f self) -> GlyphIterator<'f, 'a> {
+        GlyphIterator {
+            font: self,
+            next: 0,
+            end: 0x10FFFF,
+            wide: true,
+        }
+    }
+
+    /// Returns an iterator over the characters from `start` to `end`
+    /// inclusive that are provided by this font face, e.g. to check whether
+    /// it covers a script. Unlike `glyphs32`, no metrics are looked up.
+    /// Before SDL2_ttf 2.0.18, characters outside the Basic Multilingual
+    /// Plane are never yielded; see [SDL2_ttf versions](crate#sdl2_ttf-versions).
+    pub fn glyphs_in_range<'f>(&'f self, start: char, end: char)
+            -> impl Iterator<Item = char> + 'f {
+        (start as u32..=end as u32)
+            .filter_map(::std::char::from_u32)
+            .filter(move |&ch| self.is_glyph_provided32(ch))
+    }
+}
+
+/// A font with temporarily changed style flags, returned by
+/// `Font::apply_style_temporarily`. The previous flags are restored when
+/// it is dropped.
+pub struct StyleGuard<'f, 'a: 'f> {
+    font: &'f mut Font<'a>,
+    previous: FontStyle,
+}
+
+impl<'f, 'a> Deref for StyleGuard<'f, 'a> {
+    type Target = Font<'a>;
+
+    fn deref(&self) -> &Font<'a> {
+        self.font
+    }
+}
+
+impl<'f, 'a> DerefMut for StyleGuard<'f, 'a> {
+    fn deref_mut(&mut self) -> &mut Font<'a> {
+        self.font
+    }
+}
+
+impl<'f, 'a> Drop for StyleGuard<'f, 'a> {
+    fn drop(&mut self) {
+        self.font.set_style(self.previous);
+    }
+}
+
+/// An iterator over the glyphs provided by a font face.
+/// Code points are checked lazily, one at a time.
+pub struct GlyphIterator<'f, 'a: 'f> {
+    font: &'f Font<'a>,
+    next: u32,
+    end: u32,
+    wide: bool,
+}
+
+impl<'f, 'a> Iterator for GlyphIterator<'f, 'a> {
+    type Item = (char, GlyphMetrics);
+
+    fn next(&mut self) -> Option<(char, GlyphMetrics)> {
+        while self.next <= self.end {
+            let code = self.next;
+            self.next += 1;
+            // skips the surrogate range, which holds no characters
+            let ch = match ::std::char::from_u32(code) {
+                Some(ch) => ch,
+                None => continue,
+            };
+            let metrics = if self.wide {
+                if !self.font.is_glyph_provided32(ch) {
+                    continue;
+                }
+                self.font.find_glyph_metrics32(ch)
+            } else {
+                if !self.font.is_glyph_provided(ch) {
+                    continue;
+                }
+                self.font.find_glyph_metrics(ch)
+            };
+            if let Some(metrics) = metrics {
+                return Some((ch, metrics));
+            }
+        }
+        None
+    }
 }
@@ -1,5 +1,42 @@
 /*!
 A binding for `SDL2_ttf`.
+
+# Threading
+
+A `Font` is `Send` but not `Sync`: each font can be used from any thread,
+but only from one thread at a time, as rendering updates the font's internal
+glyph cache.
+
+All fonts share one FreeType library, which FreeType does not lock while
+fonts are opened or closed. This crate does instead: loading and dropping
+fonts, and initializing and cleaning up SDL2_ttf, take an internal lock, so
+threads rendering text concurrently can each load their own fonts. They can
+also share one font as a `SharedFont`.
+
+# Rendering
+
+Text is rendered in *solid*, *shaded*, *blended* or, since SDL2_ttf 2.20,
+*LCD* mode, each with a wrapped variant:
+
+* UTF-8 and Latin-1 text, and single characters, through the builders
+  returned by `Font::render`, `Font::render_latin1` and `Font::render_char`.
+* UCS-2 text through the `Font::render_unicode_*` functions.
+* Single glyphs through `Font::render_glyph_*` for UCS-2 code units, and
+  `Font::render_glyph32_*` for any character. Glyphs are never wrapped.
+
+# SDL2_ttf versions
+
+The API of SDL2_ttf 2.0.14 is always available. The API added by newer
+releases is compiled in when building against them, which the build script
+detects through `pkg-config`. Where it is not available, the `ttf-2-0-18`,
+`ttf-2-20-0` and `ttf-2-22-0` features select the version by hand.
+
+Items documented as requiring SDL2_ttf ≥ 2.0.18, ≥ 2.20.0 or ≥ 2.22.0 are only
+compiled in when `pkg-config` reports at least that version, or when the
+matching feature forces it. Forcing a feature does not make an older SDL2_ttf
+provide those functions, and linking then fails, so only enable the feature of
+the release actually linked against. Items with a fallback for older releases
+document what the fallback does.
  */
 
 extern crate sdl2;
@@ -12,6 +49,11 @@ extern crate bitflags;
 mod ffi;
 mod font;
 mod context;
+mod wrap;
+mod chain;
+mod atlas;
+mod color;
+mod shared;
 
 // Setup linking for all targets.
 #[cfg(target_os="macos")]
@@ -31,10 +73,45 @@ mod others {
     extern {}
 }
 
+// On Android, SDL2_ttf is linked as the static library libSDL2_ttf.a built
+// by the NDK, so the app has no extra shared library to load before the Rust
+// code runs. FreeType, which the NDK builds as a separate static library, has
+// to be linked as well. There is no SDL2_ttf framework for Android.
+#[cfg(target_os="android")]
+mod android {
+    #[link(name="SDL2_ttf", kind="static")]
+    extern {}
+}
+
+// On iOS, apps cannot ship shared libraries, so SDL2_ttf must be linked as the
+// static library libSDL2_ttf.a, with FreeType built into it. There is no
+// SDL2_ttf framework for iOS.
+#[cfg(target_os="ios")]
+mod ios {
+    #[link(name="SDL2_ttf", kind="static")]
+    extern {}
+}
+
+// Under emscripten, SDL2_ttf is an emscripten port rather than a library
+// to link against: it is pulled in by linking with `-s USE_SDL_TTF=2`.
+
 pub use context::{
-    init, has_been_initialized, get_linked_version, Sdl2TtfContext, InitError,
+    init, has_been_initialized, get_linked_version, linked_version_tuple, requires_ttf_version,
+    set_byte_swapped_unicode, Sdl2TtfContext, InitError, LoaderRWops,
 };
 pub use font::{
-    Font, FontStyle, Hinting, GlyphMetrics, PartialRendering, FontError,
-    FontResult, STYLE_NORMAL, STYLE_BOLD, STYLE_ITALIC, STYLE_UNDERLINE, STYLE_STRIKETHROUGH
+    Font, FontStyle, Hinting, GlyphMetrics, GlyphIterator, PartialRendering, RenderMode,
+    TextMetrics, MeasureResult, SolidSurface, EmbeddedFont, StyleGuard, FontError, FontResult,
+    render_fitting, STYLE_NORMAL, STYLE_BOLD, STYLE_ITALIC, STYLE_UNDERLINE, STYLE_STRIKETHROUGH
 };
+#[cfg(sdl2_ttf_version_gte_2_0_18)]
+pub use font::SdfSurface;
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+pub use font::Direction;
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+pub use font::WrappedAlign;
+pub use wrap::WordWrapper;
+pub use chain::FontChain;
+pub use atlas::{GlyphAtlas, AtlasTexture};
+pub use color::ColorExt;
+pub use shared::SharedFont;
@@ -0,0 +1,41 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use sdl2::pixels::Color;
+use sdl2::surface::Surface;
+
+use font::{Font, FontResult};
+
+/// A font which can be shared between threads, by locking it for the
+/// duration of each use.
+///
+/// Only one thread renders at a time, so threads rendering a lot of text
+/// contend for the lock: fonts loaded for each thread render concurrently
+/// instead.
+/// The font is closed by whichever thread drops the last clone.
+pub struct SharedFont<'a>(Arc<Mutex<Font<'a>>>);
+
+impl<'a> SharedFont<'a> {
+    /// Wraps the given font to share it.
+    pub fn new(font: Font<'a>) -> SharedFont<'a> {
+        SharedFont(Arc::new(Mutex::new(font)))
+    }
+
+    /// Locks the font until the returned guard goes out of scope, e.g. to
+    /// render with it several times in a row, or to change its settings.
+    pub fn lock(&self) -> MutexGuard<'_, Font<'a>> {
+        // the font holds no invariant a panicking thread could break
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Renders the given UTF-8-encoded text in *blended* mode, locking the
+    /// font while rendering.
+    pub fn render_str_blended<'b, T>(&self, text: &str, fg: T) -> FontResult<Surface<'b>>
+            where T: Into<Color> {
+        self.lock().render(text).blended(fg)
+    }
+}
+
+impl<'a> Clone for SharedFont<'a> {
+    fn clone(&self) -> SharedFont<'a> {
+        SharedFont(self.0.clone())
+    }
+}
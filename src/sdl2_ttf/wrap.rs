@@ -0,0 +1,78 @@
+use font::{Font, FontResult};
+
+/// Splits text into lines no wider than a given width, without rendering it.
+///
+/// Unlike the *wrapped* rendering modes, this hands the line breaks back to
+/// the caller, so each line can be positioned or styled on its own.
+pub struct WordWrapper<'f, 'a: 'f> {
+    font: &'f Font<'a>,
+    max_width: u32,
+}
+
+impl<'f, 'a> WordWrapper<'f, 'a> {
+    /// Creates a word wrapper measuring text with the given font.
+    pub fn new(font: &'f Font<'a>, max_width: u32) -> WordWrapper<'f, 'a> {
+        WordWrapper {
+            font: font,
+            max_width: max_width,
+        }
+    }
+
+    /// Splits the given text into lines, greedily adding words to a line as
+    /// long as it fits within the maximum width.
+    /// Newlines always start a new line. A single word wider than the maximum
+    /// width is put on a line of its own rather than being broken up.
+    pub fn wrap<'t>(&self, text: &'t str) -> FontResult<Vec<&'t str>> {
+        let mut lines = Vec::new();
+        for paragraph in text.split('\n') {
+            try!(self.wrap_paragraph(paragraph.trim_end_matches('\r'), &mut lines));
+        }
+        Ok(lines)
+    }
+
+    /// Wraps a single paragraph without newlines into `lines`.
+    fn wrap_paragraph<'t>(&self, paragraph: &'t str, lines: &mut Vec<&'t str>)
+            -> FontResult<()> {
+        // byte offsets of the current line's start and of its last word's end
+        let mut line: Option<(usize, usize)> = None;
+        for (word_start, word_end) in words(paragraph) {
+            line = match line {
+                None => Some((word_start, word_end)),
+                Some((line_start, line_end)) => {
+                    let (width, _) = try!(self.font.size_of(&paragraph[line_start..word_end]));
+                    if width <= self.max_width {
+                        Some((line_start, word_end))
+                    } else {
+                        lines.push(&paragraph[line_start..line_end]);
+                        Some((word_start, word_end))
+                    }
+                }
+            };
+        }
+        match line {
+            Some((line_start, line_end)) => lines.push(&paragraph[line_start..line_end]),
+            None => lines.push(""),
+        }
+        Ok(())
+    }
+}
+
+/// Returns the byte ranges of the whitespace-separated words of `text`.
+fn words(text: &str) -> Vec<(usize, usize)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (offset, ch) in text.char_indices() {
+        match (start, ch.is_whitespace()) {
+            (None, false) => start = Some(offset),
+            (Some(word_start), true) => {
+                words.push((word_start, offset));
+                start = None;
+            },
+            _ => {},
+        }
+    }
+    if let Some(word_start) = start {
+        words.push((word_start, text.len()));
+    }
+    words
+}
@@ -0,0 +1,42 @@
+//! Fixtures shared by the integration tests.
+
+// each test crate includes this module, and uses only some of it
+#![allow(dead_code)]
+
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+
+use sdl2_ttf::{Font, Sdl2TtfContext};
+
+/// Held by each test while it uses SDL2_ttf.
+static SDL2_TTF: Mutex<()> = Mutex::new(());
+
+/// The font the tests render with, DejaVu Sans Mono, bundled in
+/// `tests/fonts` under the license next to it.
+pub const TEST_FONT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fonts/DejaVuSansMono.ttf");
+
+/// A font covering characters the test font lacks, such as U+01C4, to
+/// test fallbacks with: DejaVu Sans, bundled in `tests/fonts` as well.
+pub const FALLBACK_FONT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fonts/DejaVuSans.ttf");
+
+/// Loads the bundled test font at the given point size.
+/// The font borrows the context, which keeps SDL2_ttf initialized for as
+/// long as the font is used.
+pub fn load_test_font<'a>(context: &'a Sdl2TtfContext, point_size: u16) -> Font<'a> {
+    context.load_font(Path::new(TEST_FONT), point_size).unwrap()
+}
+
+/// Loads the bundled fallback font at the given point size.
+pub fn load_fallback_font<'a>(context: &'a Sdl2TtfContext, point_size: u16) -> Font<'a> {
+    context.load_font(Path::new(FALLBACK_FONT), point_size).unwrap()
+}
+
+/// Locks SDL2_ttf for the calling test until the guard is dropped.
+/// Cargo runs the tests of a binary in parallel, but neither initializing and
+/// cleaning up SDL2_ttf nor opening and closing fonts in the FreeType library
+/// it shares between them is thread-safe.
+/// Take the lock before creating a context, so that it is released last.
+pub fn lock_sdl2_ttf() -> MutexGuard<'static, ()> {
+    // a failed test poisons the lock, which says nothing about SDL2_ttf
+    SDL2_TTF.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
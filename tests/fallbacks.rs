@@ -0,0 +1,26 @@
+extern crate sdl2;
+extern crate sdl2_ttf;
+
+mod common;
+
+use sdl2::pixels::Color;
+use sdl2_ttf::{RenderMode, Sdl2TtfContext};
+
+use common::{load_fallback_font, load_test_font, lock_sdl2_ttf};
+
+#[test]
+fn switches_back_to_the_primary_font() {
+    let _lock = lock_sdl2_ttf();
+    let context = Sdl2TtfContext::init_or_get().unwrap();
+    let font = load_test_font(&context, 16);
+    // a larger fallback font, so that text it renders by mistake is wider
+    let fallback = load_fallback_font(&context, 32);
+    assert!(!font.is_glyph_provided32('\u{1C4}'));
+    assert!(fallback.is_glyph_provided32('\u{1C4}'));
+
+    let surface = font.render_str_with_fallbacks("ab\u{1C4}cd", &[&fallback],
+        Color::RGB(255, 255, 255), RenderMode::Blended).unwrap();
+    let expected = font.size_of("ab").unwrap().0 + fallback.size_of("\u{1C4}").unwrap().0
+        + font.size_of("cd").unwrap().0;
+    assert_eq!(surface.width(), expected);
+}
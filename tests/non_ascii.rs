@@ -0,0 +1,105 @@
+extern crate sdl2;
+extern crate sdl2_ttf;
+
+mod common;
+
+use sdl2::pixels::Color;
+use sdl2_ttf::{Font, RenderMode, Sdl2TtfContext};
+
+#[cfg(sdl2_ttf_version_gte_2_0_18)]
+use common::load_fallback_font;
+use common::{load_test_font, lock_sdl2_ttf};
+
+/// Checks that the given text has a size and renders to a non-empty
+/// surface. Characters the font lacks still take the room of its
+/// replacement glyph.
+fn assert_renders(font: &Font, text: &str) {
+    let (width, height) = font.size_of(text).unwrap();
+    assert!(width > 0 && height > 0, "{:?} has no size", text);
+    let surface = font.render(text).blended(Color::RGB(255, 255, 255)).unwrap();
+    assert!(surface.width() > 0 && surface.height() > 0, "{:?} rendered empty", text);
+}
+
+#[test]
+fn renders_cjk_text_missing_from_the_font() {
+    let _lock = lock_sdl2_ttf();
+    let context = Sdl2TtfContext::init_or_get().unwrap();
+    let font = load_test_font(&context, 16);
+    let text = "\u{4E00}\u{4E8C}\u{4E09}";
+    // neither bundled font covers CJK
+    assert_eq!(font.glyphs_missing_from_str(text), vec!['\u{4E00}', '\u{4E8C}', '\u{4E09}']);
+    assert_renders(&font, text);
+
+    // with no font providing them, they are replaced by U+FFFD
+    assert!(font.is_glyph_provided('\u{FFFD}'));
+    let surface = font.render_str_with_fallbacks(text, &[], Color::RGB(255, 255, 255),
+        RenderMode::Blended).unwrap();
+    assert_eq!(surface.width(), font.size_of("\u{FFFD}\u{FFFD}\u{FFFD}").unwrap().0);
+}
+
+#[test]
+fn renders_arabic_text() {
+    let _lock = lock_sdl2_ttf();
+    let context = Sdl2TtfContext::init_or_get().unwrap();
+    let font = load_test_font(&context, 16);
+    let text = "\u{645}\u{631}\u{62D}\u{628}\u{627}";
+    assert_eq!(font.glyphs_missing_from_str(text), vec![]);
+    assert_renders(&font, text);
+}
+
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+#[test]
+fn renders_arabic_text_right_to_left() {
+    let _lock = lock_sdl2_ttf();
+    let context = Sdl2TtfContext::init_or_get().unwrap();
+    let mut font = load_test_font(&context, 16);
+    let surface = font.render_str_blended_with_direction("\u{645}\u{631}\u{62D}\u{628}\u{627}",
+        Color::RGB(255, 255, 255), sdl2_ttf::Direction::RightToLeft).unwrap();
+    assert!(surface.width() > 0 && surface.height() > 0);
+}
+
+#[test]
+fn renders_emoji_missing_from_the_font() {
+    let _lock = lock_sdl2_ttf();
+    let context = Sdl2TtfContext::init_or_get().unwrap();
+    let font = load_test_font(&context, 16);
+    assert!(!font.is_glyph_provided32('\u{1F600}'));
+    assert_renders(&font, "\u{1F600}");
+}
+
+#[cfg(sdl2_ttf_version_gte_2_0_18)]
+#[test]
+fn renders_emoji_with_a_fallback_font() {
+    let _lock = lock_sdl2_ttf();
+    let context = Sdl2TtfContext::init_or_get().unwrap();
+    let font = load_test_font(&context, 16);
+    let fallback = load_fallback_font(&context, 16);
+    assert!(!font.is_glyph_provided32('\u{1F600}'));
+    assert!(fallback.is_glyph_provided32('\u{1F600}'));
+
+    let surface = font.render_str_with_fallbacks("\u{1F600}", &[&fallback],
+        Color::RGB(255, 255, 255), RenderMode::Blended).unwrap();
+    assert_eq!(surface.width(), fallback.size_of("\u{1F600}").unwrap().0);
+}
+
+#[test]
+fn renders_combining_diacritics() {
+    let _lock = lock_sdl2_ttf();
+    let context = Sdl2TtfContext::init_or_get().unwrap();
+    let font = load_test_font(&context, 16);
+    assert_renders(&font, "e\u{301}");
+}
+
+/// Shaping draws the accent over the base letter rather than next to it, so
+/// it takes no room of its own in this monospace font. Without shaping, the
+/// advance of the accent's glyph, as wide as any other, is added.
+#[cfg(sdl2_ttf_version_gte_2_20_0)]
+#[test]
+fn combining_diacritics_take_no_room() {
+    let _lock = lock_sdl2_ttf();
+    let context = Sdl2TtfContext::init_or_get().unwrap();
+    let font = load_test_font(&context, 16);
+    let (combined, _) = font.size_of("e\u{301}").unwrap();
+    let (base, _) = font.size_of("e").unwrap();
+    assert_eq!(combined, base);
+}
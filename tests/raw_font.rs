@@ -0,0 +1,42 @@
+extern crate sdl2;
+extern crate sdl2_ttf;
+
+mod common;
+
+use sdl2::pixels::Color;
+use sdl2_ttf::{Font, Sdl2TtfContext};
+
+use common::{load_test_font, lock_sdl2_ttf};
+
+#[test]
+fn dropping_a_borrowed_font_does_not_close_it() {
+    let _lock = lock_sdl2_ttf();
+    let context = Sdl2TtfContext::init_or_get().unwrap();
+    let raw = load_test_font(&context, 16).into_raw();
+    let white = Color::RGB(255, 255, 255);
+
+    let borrowed = unsafe { Font::from_raw(raw, false) };
+    let before = borrowed.render("Hello").blended(white).unwrap().size();
+    drop(borrowed);
+
+    // the font is still open, and closed once an owning wrapper is dropped
+    let font = unsafe { Font::from_raw(raw, true) };
+    let after = font.render("Hello").blended(white).unwrap().size();
+    assert_eq!(after, before);
+}
+
+#[test]
+fn fonts_are_equal_only_to_themselves() {
+    let _lock = lock_sdl2_ttf();
+    let context = Sdl2TtfContext::init_or_get().unwrap();
+    let font = load_test_font(&context, 16);
+    let other = load_test_font(&context, 16);
+    let same = &font;
+    assert!(font == *same);
+    assert!(font != other);
+
+    let raw = font.into_raw();
+    let font = unsafe { Font::from_raw(raw, true) };
+    let alias = unsafe { Font::from_raw(raw, false) };
+    assert!(font == alias);
+}